@@ -0,0 +1,72 @@
+use aoc_core::{Answer, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed = Vec<i32>;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        parse_input(input)
+    }
+    fn part1(numbers: &Self::Parsed) -> Answer {
+        part1(numbers).into()
+    }
+    fn part2(numbers: &Self::Parsed) -> Answer {
+        part2(numbers).into()
+    }
+}
+
+fn parse_input(input: &str) -> aoc_core::Result<Vec<i32>> {
+    input
+        .lines()
+        .map(|n| {
+            n.parse::<i32>()
+                .map_err(|_| aoc_core::ParseError::at(input, n, "a depth measurement").into())
+        })
+        .collect()
+}
+
+fn part1(numbers: &[i32]) -> i32 {
+    let mut prev = i32::MAX;
+
+    numbers.iter().fold(0i32, |sum, val| {
+        let result = if val > &prev { sum + 1 } else { sum };
+        prev = *val;
+        result
+    })
+}
+
+#[allow(dead_code)]
+fn part1_windows(numbers: &[i32]) -> i32 {
+    numbers.windows(2).filter(|x| x[0] < x[1]).count() as i32
+}
+
+fn part2(numbers: &[i32]) -> i32 {
+    let mut prev = i32::MAX;
+
+    let sums: Vec<i32> = numbers.windows(3).map(|x| x[0] + x[1] + x[2]).collect();
+
+    sums.iter().fold(0i32, |sum, val| {
+        let result = if val > &prev { sum + 1 } else { sum };
+        prev = *val;
+        result
+    })
+}
+
+#[allow(dead_code)]
+fn part2_windows(numbers: &[i32]) -> i32 {
+    let sums: Vec<i32> = numbers.windows(3).map(|x| x[0] + x[1] + x[2]).collect();
+    sums.windows(2).filter(|x| x[0] < x[1]).count() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part2_short_input() {
+        assert_eq!(part2(&[]), 0);
+        assert_eq!(part2(&[199, 200]), 0);
+        assert_eq!(part2(&[199, 200, 208, 210]), 1);
+    }
+}
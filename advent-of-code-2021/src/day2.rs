@@ -0,0 +1,113 @@
+use aoc_core::{Answer, ParseError, Solution, Span};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Parsed = Vec<Command>;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_input(input)?)
+    }
+    fn part1(commands: &Self::Parsed) -> Answer {
+        part1(commands).result().into()
+    }
+    fn part2(commands: &Self::Parsed) -> Answer {
+        part2(commands).result().into()
+    }
+}
+
+#[derive(Default)]
+struct Position {
+    x: i32,
+    depth: i32,
+    aim: i32,
+}
+
+impl Position {
+    fn simple_execute(&mut self, command: &Command) {
+        match command {
+            Command::Forward(v) => self.x += v,
+            Command::Down(v) => self.depth += v,
+            Command::Up(v) => self.depth -= v,
+        };
+    }
+
+    fn execute(&mut self, command: &Command) {
+        match command {
+            Command::Forward(v) => {
+                self.x += v;
+                self.depth += v * self.aim;
+            }
+            Command::Down(v) => self.aim += v,
+            Command::Up(v) => self.aim -= v,
+        }
+    }
+
+    fn result(&self) -> i32 {
+        self.x * self.depth
+    }
+}
+
+#[derive(Debug)]
+pub enum Command {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+}
+
+fn parse_input(input: &str) -> Result<Vec<Command>, ParseError> {
+    Span::new(input).lines_with(|line| {
+        let (direction, val) = line.split_once(" ")?;
+        let val = val.parse::<i32>()?;
+        match direction.as_str() {
+            "forward" => Ok(Command::Forward(val)),
+            "down" => Ok(Command::Down(val)),
+            "up" => Ok(Command::Up(val)),
+            _ => Err(direction.error("`forward`, `down` or `up`")),
+        }
+    })
+}
+
+fn part1(commands: &[Command]) -> Position {
+    let mut position = Position::default();
+    for c in commands {
+        position.simple_execute(c);
+    }
+
+    position
+}
+
+fn part2(commands: &[Command]) -> Position {
+    let mut position = Position::default();
+    for c in commands {
+        position.execute(c);
+    }
+
+    position
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part2() {
+        let commands = vec![
+            Command::Forward(5),
+            Command::Down(5),
+            Command::Forward(8),
+            Command::Up(3),
+            Command::Down(8),
+            Command::Forward(2),
+        ];
+        assert_eq!(part2(&commands).result(), 900);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse_input("forward 5\nback 3").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        let e = parse_input("forward 5\ndown x").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 6));
+    }
+}
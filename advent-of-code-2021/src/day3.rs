@@ -0,0 +1,126 @@
+use aoc_core::{Answer, ParseError, Solution, Span};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_input(input)?)
+    }
+    fn part1(signals: &Self::Parsed) -> Answer {
+        let (gamma, epsilon) = part1(signals);
+        (gamma * epsilon).into()
+    }
+    fn part2(_signals: &Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+/// The diagnostic report: one binary number per line, all of the same
+/// width, which has to fit the rates' `i32`.
+fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    let mut width = None;
+    let signals = Span::new(input).lines_with(|line| {
+        let text = line.as_str();
+        let width = *width.get_or_insert(text.len());
+        if text.is_empty() || text.len() != width || text.len() > 31 {
+            return Err(line.error(format!("a number of {} binary digits", width.clamp(1, 31))));
+        }
+        if let Some(i) = text.find(|c| c != '0' && c != '1') {
+            return Err(ParseError::at(input, &text[i..i + 1], "a binary digit"));
+        }
+        Ok(text.to_string())
+    })?;
+    if signals.is_empty() {
+        return Err(ParseError::at(input, "", "a binary number"));
+    }
+    Ok(signals)
+}
+
+fn part1(inputs: &[String]) -> (i32, i32) {
+    let num_digits = inputs[0].len();
+    let mut counts = vec![0; num_digits];
+
+    for num in inputs {
+        let bits: Vec<u32> = num.chars().map(|c| c.to_digit(2).unwrap()).collect();
+
+        for (i, val) in bits.into_iter().enumerate() {
+            counts[i] += if val == 1 {
+                1
+            } else {
+                -1
+            };
+        }
+    }
+
+    let mut gamma = String::new();
+    let mut epsilon = String::new();
+    for n in counts {
+        if n > 0 {
+            gamma.push('1');
+            epsilon.push('0');
+        } else {
+            gamma.push('0');
+            epsilon.push('1')
+        }
+    };
+
+    let gamma = i32::from_str_radix(&gamma, 2).unwrap();
+    let epsilon = i32::from_str_radix(&epsilon, 2).unwrap();
+
+    (gamma, epsilon)
+}
+
+#[allow(dead_code)]
+fn part2(inputs: &[String]) -> (i32, i32) {
+    let num_digits = inputs[0].len();
+    let mut counts = vec![0; num_digits];
+
+    for num in inputs {
+        let bits: Vec<u32> = num.chars().map(|c| c.to_digit(2).unwrap()).collect();
+
+        for (i, val) in bits.into_iter().enumerate() {
+            counts[i] += if val == 1 {
+                1
+            } else {
+                -1
+            };
+        }
+    }
+
+    let mut inputs = inputs.to_vec();
+    let index = 0;
+    while inputs.len() == 1 {
+        inputs = inputs.iter().filter(|s| {
+            s.as_bytes()[index] == b'1'
+        }).cloned().collect();
+    }
+
+    println!("inputs: {:?}", inputs);
+    let oxygen_generator = 0;
+    let co2_scrubber = 0;
+
+    (oxygen_generator, co2_scrubber)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let signals = parse_input(&crate::read_example(3, "1").unwrap()).unwrap();
+        assert_eq!(part1(&signals), (22, 9));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse_input("00100\n11110\n1011").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 1));
+        let e = parse_input("00100\n11210").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
+        let e = parse_input("").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
+    }
+}
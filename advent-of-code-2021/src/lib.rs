@@ -0,0 +1,8 @@
+pub const YEAR: u16 = 2021;
+pub const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
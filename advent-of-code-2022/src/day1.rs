@@ -0,0 +1,57 @@
+use aoc_core::{Answer, ParseError, Solution, Span};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed = Vec<i32>;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        let calories = parse_input(input)?;
+        Ok(calories.iter().map(|vec| vec.iter().sum()).collect())
+    }
+    fn part1(calories: &Self::Parsed) -> Answer {
+        part1(calories).into()
+    }
+    fn part2(calories: &Self::Parsed) -> Answer {
+        part2(calories).into()
+    }
+}
+
+fn part1(calories: &[i32]) -> i32 {
+    *calories.iter().max().unwrap()
+}
+
+fn part2(calories: &[i32]) -> i32 {
+    let sorted = get_sorted(calories);
+    let top_3_elves = sorted.chunks(3).last().unwrap();
+    top_3_elves.iter().sum::<i32>()
+}
+
+/// Each elf's calories, one per line, with a blank line between elves.
+fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    let elves = Span::new(input).blocks_with(|elf| elf.lines_with(Span::parse))?;
+    if elves.is_empty() {
+        return Err(ParseError::at(input, "", "an elf's calories"));
+    }
+    Ok(elves)
+}
+
+fn get_sorted(vec: &[i32]) -> Vec<i32> {
+    let mut new_vec = vec.to_owned();
+    new_vec.sort();
+
+    new_vec
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse_input("1000\n2000\n\n4000\n5x00").unwrap_err();
+        assert_eq!((e.line, e.column), (5, 1));
+        let e = parse_input("").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
+    }
+}
@@ -0,0 +1,151 @@
+use aoc_core::{Answer, ParseError, Solution, Span};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Shape {
+    Rock,
+    Paper,
+    Scissor,
+}
+
+impl Shape {
+    /// The opponent's `A`, `B` or `C`.
+    fn from_opponent(span: Span) -> std::result::Result<Shape, ParseError> {
+        match span.as_str() {
+            "A" => Ok(Shape::Rock),
+            "B" => Ok(Shape::Paper),
+            "C" => Ok(Shape::Scissor),
+            _ => Err(span.error("`A`, `B` or `C`")),
+        }
+    }
+
+    /// The guide's `X`, `Y` or `Z`, read as a shape to play.
+    fn from_response(span: Span) -> std::result::Result<Shape, ParseError> {
+        match span.as_str() {
+            "X" => Ok(Shape::Rock),
+            "Y" => Ok(Shape::Paper),
+            "Z" => Ok(Shape::Scissor),
+            _ => Err(span.error("`X`, `Y` or `Z`")),
+        }
+    }
+
+    fn is_win(me: &Shape, opp: &Shape) -> bool {
+        match me {
+            Shape::Rock => opp == &Shape::Scissor ,
+            Shape::Paper => opp == &Shape::Rock ,
+            Shape::Scissor => opp == &Shape::Paper ,
+        }
+    }
+
+    fn is_draw(me: &Shape, opp: &Shape) -> bool {
+        me == opp
+    }
+
+    fn get_match_score(&self, opp: &Shape) -> i32 {
+        if Shape::is_draw(self, opp) {
+            3
+        } else if Shape::is_win(self, opp) {
+            6
+        } else {
+            0
+        }
+    }
+
+    fn get_my_score(&self) -> i32 {
+        match &self {
+            Shape::Rock => 1,
+            Shape::Paper => 2,
+            Shape::Scissor => 3,
+        }
+    }
+
+    fn what_to_win(&self) -> Shape {
+        match &self {
+            Shape::Rock => Shape::Paper,
+            Shape::Paper => Shape::Scissor,
+            Shape::Scissor => Shape::Rock,
+        }
+    }
+
+    fn what_to_draw(&self) -> Shape {
+        self.clone()
+    }
+
+    fn what_to_lose(&self) -> Shape {
+        match &self {
+            Shape::Rock => Shape::Scissor,
+            Shape::Paper => Shape::Rock,
+            Shape::Scissor => Shape::Paper,
+        }
+    }
+
+}
+
+enum Result {
+    Win,
+    Lose,
+    Draw,
+}
+
+/// The guide's second column read as how the round should end instead:
+/// `X` (rock) means lose, `Y` (paper) draw and `Z` (scissors) win.
+impl From<&Shape> for Result {
+    fn from(val: &Shape) -> Result {
+        match val {
+            Shape::Rock => Result::Lose,
+            Shape::Paper => Result::Draw,
+            Shape::Scissor => Result::Win,
+        }
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Parsed = Vec<(Shape, Shape)>;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_input(input)?)
+    }
+    fn part1(matches: &Self::Parsed) -> Answer {
+        part1(matches).into()
+    }
+    fn part2(matches: &Self::Parsed) -> Answer {
+        part2(matches).into()
+    }
+}
+
+fn part1(matches: &[(Shape, Shape)]) -> i32 {
+    matches.iter().map(|(opp, me)| {
+        me.get_my_score() + me.get_match_score(opp)
+    }).sum()
+}
+
+fn part2(matches: &[(Shape, Shape)]) -> i32 {
+    matches.iter().map(|(opp, response)| {
+        let result: Result = response.into();
+        let my_decision = match result {
+            Result::Win => opp.what_to_win(),
+            Result::Draw => opp.what_to_draw(),
+            Result::Lose => opp.what_to_lose(),
+        };
+        my_decision.get_my_score() + my_decision.get_match_score(opp)
+    }).sum()
+}
+
+fn parse_input(input: &str) -> std::result::Result<Vec<(Shape, Shape)>, ParseError> {
+    Span::new(input)
+        .lines_with(|line| line.pair_with(" ", Shape::from_opponent, Shape::from_response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse_input("A Y\nB X\nC W").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 3));
+        let e = parse_input("A Y\nBX").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+    }
+}
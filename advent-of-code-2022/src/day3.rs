@@ -0,0 +1,129 @@
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use aoc_core::{Answer, ParseError, Solution, Span};
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_input(input)?)
+    }
+    fn part1(rucksacks: &Self::Parsed) -> Answer {
+        part1(rucksacks).into()
+    }
+    fn part2(rucksacks: &Self::Parsed) -> Answer {
+        part2(rucksacks).into()
+    }
+}
+
+fn part1(rucksacks: &[String]) -> i32 {
+    let priority_map = create_priority_map();
+    rucksacks
+        .iter()
+        .map(|v| {
+            let (first, second) = get_compartment(v);
+            let (first_chars, second_chars) = (string_to_btreeset(&first), string_to_btreeset(&second));
+
+            let common: Vec<char> = first_chars.intersection(&second_chars).cloned().collect();
+            priority_map.get(&common[0]).unwrap()
+        })
+        .sum()
+}
+
+fn part2(rucksacks: &[String]) -> i32 {
+    let priority_map = create_priority_map();
+    rucksacks
+        .chunks(3)
+        .map(|chunk| {
+            let first_chars = string_to_btreeset(&chunk[0]);
+            let second_chars = string_to_btreeset(&chunk[1]);
+            let third_chars = string_to_btreeset(&chunk[2]);
+
+            let temp: Vec<char> = first_chars.intersection(&second_chars).cloned().collect();
+            let common: Vec<char> = vec_to_btreeset(&temp)
+                .intersection(&third_chars)
+                .cloned()
+                .collect();
+
+            priority_map.get(&common[0]).unwrap()
+        })
+        .sum()
+}
+
+fn string_to_btreeset(charvec: &str) -> BTreeSet<char> {
+    charvec.chars().collect()
+}
+
+fn vec_to_btreeset(charvec: &[char]) -> BTreeSet<char> {
+    charvec.iter().copied().collect()
+}
+
+fn get_compartment(rucksack: &str) -> (String, String) {
+    let split = rucksack
+        .chars()
+        .collect::<Vec<char>>()
+        .chunks(rucksack.len() / 2)
+        .map(|chunk| chunk.iter().collect::<String>())
+        .collect::<Vec<String>>();
+    (split[0].clone(), split[1].clone())
+}
+
+fn create_priority_map() -> HashMap<char, i32> {
+    let alphabet = String::from_utf8((b'a'..=b'z').chain(b'A'..=b'Z').collect()).unwrap();
+
+    let mut result = HashMap::new();
+    for (i, ch) in alphabet.chars().enumerate() {
+        result.insert(ch, i as i32 + 1);
+    }
+
+    result
+}
+
+/// The rucksacks, each an even number of item letters whose two halves
+/// share an item, in groups of three that share a badge.
+fn parse_input(input: &str) -> Result<Vec<String>, ParseError> {
+    let rucksacks = Span::new(input).lines_with(|line| {
+        let items = line.as_str();
+        if let Some(i) = items.find(|c: char| !c.is_ascii_alphabetic()) {
+            return Err(ParseError::at(input, &items[i..i + 1], "an item letter"));
+        }
+        let (first, second) = items.split_at(items.len() / 2);
+        if items.len() % 2 != 0 || !first.chars().any(|c| second.contains(c)) {
+            return Err(line.error("two equal halves sharing an item"));
+        }
+        Ok(items)
+    })?;
+
+    if rucksacks.len() % 3 != 0 {
+        return Err(ParseError::at(input, "", "a full group of three rucksacks"));
+    }
+    for group in rucksacks.chunks(3) {
+        let badge = group[0]
+            .chars()
+            .find(|&c| group[1].contains(c) && group[2].contains(c));
+        if badge.is_none() {
+            return Err(ParseError::at(input, group[0], "a group sharing a badge"));
+        }
+    }
+
+    Ok(rucksacks.into_iter().map(String::from).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse_input("vJrwpWtwJgWr\nhcsFMMf-hFp").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 8));
+        let e = parse_input("abcdea\nab").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        let e = parse_input("aa\nbb").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
+        let e = parse_input("aa\nbb\ncc").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
+    }
+}
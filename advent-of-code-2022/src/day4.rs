@@ -0,0 +1,70 @@
+use aoc_core::{Answer, Interval, ParseError, Solution, Span};
+
+#[derive(Debug)]
+pub struct Section(Interval<i32>);
+
+impl Section {
+    /// A `first-last` range of section ids.
+    fn parse(span: Span) -> Result<Self, ParseError> {
+        let (first, last): (i32, i32) = span.pair("-")?;
+        if first > last || last == i32::MAX {
+            return Err(span.error("a range from a section id to one no smaller"));
+        }
+        Ok(Section(Interval::inclusive(first, last)))
+    }
+
+    fn fully_contains(&self, opp: &Section) -> bool {
+        self.0.contains_interval(&opp.0) || opp.0.contains_interval(&self.0)
+    }
+
+    fn is_overlap(&self, opp: &Section) -> bool {
+        self.0.overlaps(&opp.0)
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed = Vec<(Section, Section)>;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_input(input)?)
+    }
+    fn part1(pairs: &Self::Parsed) -> Answer {
+        part1(pairs).into()
+    }
+    fn part2(pairs: &Self::Parsed) -> Answer {
+        part2(pairs).into()
+    }
+}
+
+fn part1(pairs: &[(Section, Section)]) -> usize {
+    pairs
+        .iter()
+        .filter(|sections| sections.0.fully_contains(&sections.1))
+        .count()
+}
+
+fn part2(pairs: &[(Section, Section)]) -> usize {
+    pairs
+        .iter()
+        .filter(|sections| sections.0.is_overlap(&sections.1))
+        .count()
+}
+
+fn parse_input(input: &str) -> Result<Vec<(Section, Section)>, ParseError> {
+    Span::new(input).lines_with(|line| line.pair_with(",", Section::parse, Section::parse))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse_input("2-4,6-8\n2-3;4-5").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        let e = parse_input("2-4,6-8\n2-3,5-4").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 5));
+    }
+}
@@ -0,0 +1,118 @@
+use aoc_core::{Answer, ParseError, Solution, Span};
+
+#[derive(Debug)]
+pub struct Command {
+    num_crates: i32,
+    from: i32,
+    to: i32,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed = Vec<Command>;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_input(input)?)
+    }
+    fn part1(commands: &Self::Parsed) -> Answer {
+        part1(commands).into()
+    }
+    fn part2(commands: &Self::Parsed) -> Answer {
+        part2(commands).into()
+    }
+}
+
+fn part1(commands: &[Command]) -> String {
+    let mut stacks = get_initial_stacks();
+    for command in commands {
+        for _ in 0..command.num_crates {
+            let value = stacks[command.from as usize - 1].pop().unwrap();
+            stacks[command.to as usize - 1].push(value);
+        }
+    }
+    stacks.into_iter().filter_map(|mut vec| vec.pop()).collect()
+}
+
+fn part2(commands: &[Command]) -> String {
+    let mut stacks = get_initial_stacks();
+    for command in commands {
+        let mut crates_to_move = Vec::new();
+        for _count in 0..command.num_crates {
+            let value = stacks[command.from as usize - 1].pop().unwrap();
+            crates_to_move.push(value);
+        }
+
+        for _ in 0..command.num_crates {
+            let value = crates_to_move.pop().unwrap();
+            stacks[command.to as usize - 1].push(value);
+        }
+    }
+    stacks.into_iter().filter_map(|mut vec| vec.pop()).collect()
+}
+
+fn get_initial_stacks() -> Vec<Vec<char>> {
+    vec![
+        vec!['S', 'Z', 'P', 'D', 'L', 'B', 'F', 'C'], // 1
+        vec!['N', 'V', 'G', 'P', 'H', 'W', 'B'],      // 2
+        vec!['F', 'W', 'B', 'J', 'G'],                // 3
+        vec!['G', 'J', 'N', 'F', 'L', 'W', 'C', 'S'], // 4
+        vec!['W', 'J', 'L', 'T', 'P', 'M', 'S', 'H'], // 5
+        vec!['B', 'C', 'W', 'G', 'F', 'S'],           // 6
+        vec!['H', 'T', 'P', 'M', 'Q', 'B', 'W'],      // 7
+        vec!['F', 'S', 'W', 'T'],                     // 8
+        vec!['N', 'C', 'R'],                          // 9
+    ]
+}
+
+/// The `move N from A to B` lines, skipping the `#` lines that draw the
+/// starting stacks. Every move has to find enough crates on its stack.
+fn parse_input(input: &str) -> Result<Vec<Command>, ParseError> {
+    let mut sizes: Vec<usize> = get_initial_stacks().iter().map(Vec::len).collect();
+    Span::new(input)
+        .lines()
+        .filter(|line| !line.as_str().starts_with('#'))
+        .map(|line| parse_command(line, &mut sizes))
+        .collect()
+}
+
+fn parse_command(line: Span, sizes: &mut [usize]) -> Result<Command, ParseError> {
+    let (num_crates, stacks) = line.strip_prefix("move")?.split_once("from")?;
+    let (from, to) = stacks.split_once("to")?;
+
+    let stack = |span: Span| -> Result<usize, ParseError> {
+        match span.parse::<usize>()? {
+            n @ 1.. if n <= sizes.len() => Ok(n - 1),
+            _ => Err(span.error(format!("a stack from 1 to {}", sizes.len()))),
+        }
+    };
+    let (from_stack, to_stack) = (stack(from)?, stack(to)?);
+    let count: usize = num_crates.parse()?;
+    if count > sizes[from_stack] {
+        let expected = format!("at most the {} crates on the stack", sizes[from_stack]);
+        return Err(num_crates.error(expected));
+    }
+    sizes[from_stack] -= count;
+    sizes[to_stack] += count;
+
+    Ok(Command {
+        num_crates: count as i32,
+        from: from_stack as i32 + 1,
+        to: to_stack as i32 + 1,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse_input("# 1 2 3\nmove 2 from 5 to 9\nmove 1 from 10 to 1").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 13));
+        let e = parse_input("move 2 from 9 to 1\nmove 2 from 9 to 1").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 6));
+        let e = parse_input("move 2 form 9 to 1").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 6));
+    }
+}
@@ -0,0 +1,28 @@
+use aoc_core::{Answer, Solution};
+use std::collections::HashSet;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed = Vec<char>;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(input.chars().collect())
+    }
+    fn part1(input: &Self::Parsed) -> Answer {
+        get_result(input, 4).into()
+    }
+    fn part2(input: &Self::Parsed) -> Answer {
+        get_result(input, 14).into()
+    }
+}
+
+fn get_result(input: &[char], num: i32) -> i32 {
+    for (i, iter) in input.windows(num as usize).enumerate() {
+        let set: HashSet<&char> = iter.iter().collect();
+        if set.len() == num as usize {
+            return i as i32 + num;
+        }
+    }
+    0
+}
@@ -1,11 +1,26 @@
+use aoc_core::{Answer, ParseError, Solution, Span};
 use std::collections::{HashMap, HashSet};
 use std::fmt::Formatter;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
 
 // FAILED: 99749
 // FAILED: 99987
 
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed = (u32, NodeList);
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(assess_the_situation_and_return_root_node(input)?)
+    }
+    fn part1(_nodes: &Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
+    fn part2(_nodes: &Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
+}
+
 #[derive(Debug)]
 enum Node {
     Directory(DirectoryNode),
@@ -100,7 +115,7 @@ struct FileNode {
 }
 
 #[derive(Debug, Default)]
-struct NodeList {
+pub struct NodeList {
     node: HashMap<u32, Node>,
     num_files: i32,
     num_dirs: i32,
@@ -108,7 +123,6 @@ struct NodeList {
 
 impl NodeList {
     fn insert_new_node(&mut self, node: Node) {
-        println!("New node added, {}", node);
         match &node {
             Node::Directory(_) => self.num_dirs += 1,
             Node::File(_) => self.num_files += 1,
@@ -124,12 +138,13 @@ impl NodeList {
         self.node.get(&id).unwrap()
     }
 
+    #[allow(dead_code)]
     fn print(&self, id: u32, depth: usize) {
         let node = self.node.get(&id).unwrap();
 
         match node {
             Node::Directory(dir) => {
-                let indent = std::iter::repeat("  ").take(depth).collect::<String>();
+                let indent = std::iter::repeat_n("  ", depth).collect::<String>();
                 println!("{}, {}", indent, node);
 
                 for n in &dir.list {
@@ -142,6 +157,7 @@ impl NodeList {
         }
     }
 
+    #[allow(dead_code)]
     fn total_nodes(&self) {
         println!(
             "Total nodes, dir:{}, files:{}",
@@ -163,6 +179,7 @@ impl IdAllocator {
     }
 }
 
+#[allow(dead_code)]
 fn test_input() -> Vec<String> {
     let input = r"$ cd /
 $ ls
@@ -189,24 +206,11 @@ $ ls
 7214296 k";
     input.lines().map(|l| l.to_string()).collect()
 }
-fn main() {
-    let lines = get_day7_input();
-    //let lines = test_input();
-
-    let (root_node_id, nodes) = assess_the_situation_and_return_root_node(lines);
-    nodes.print(root_node_id, 0);
-    nodes.total_nodes();
-    println!();
-
-    let target_num = 100000;
-    //my_first_try(root_node_id, &nodes, target_num);
-    my_second_try(root_node_id, &nodes, target_num);
-}
-
+#[allow(dead_code)]
 fn my_first_try(root_node_id: u32, nodes: &NodeList, target_num: u64) {
     // target_num 이하의 sums 를 모두 찾은 뒤 이 중 max 구함 => 결과 = 99749
     // 문제점: greedy 하게 찾아서는 max를 찾을 수 없다! (5+4 < 5+3+2)
-    let sizes = get_dir_sizes(root_node_id, &nodes);
+    let sizes = get_dir_sizes(root_node_id, nodes);
     let mut sizes: Vec<u64> = sizes
         .into_iter()
         .filter(|n| *n <= target_num && *n != 0)
@@ -216,7 +220,7 @@ fn my_first_try(root_node_id: u32, nodes: &NodeList, target_num: u64) {
     println!();
 
     let mut sums = Vec::new();
-    while sizes.len() != 0 {
+    while !sizes.is_empty() {
         let mut sum = 0;
         for num in &sizes {
             if sum + num <= target_num {
@@ -232,10 +236,11 @@ fn my_first_try(root_node_id: u32, nodes: &NodeList, target_num: u64) {
     println!("sum: {}", sums.iter().max().unwrap());
 }
 
+#[allow(dead_code)]
 fn my_second_try(root_node_id: u32, nodes: &NodeList, target_num: u64) {
     // 모든 합을 다 찾은 뒤 이 중 max 구함 => 결과 = 99987
     // 문제점: 더한 값이 같은 경우를 구분할 수 없다...! (10 + 10 = 20 이 되는 최대치를 찾을 수 없다)
-    let sizes = get_dir_sizes(root_node_id, &nodes);
+    let sizes = get_dir_sizes(root_node_id, nodes);
     let mut sizes: Vec<u64> = sizes
         .into_iter()
         .filter(|n| *n <= target_num && *n != 0)
@@ -249,7 +254,7 @@ fn my_second_try(root_node_id: u32, nodes: &NodeList, target_num: u64) {
     for _ in 0..last_sums.len() - 1 {
         let mut sums = HashSet::new();
         for current in &last_sums {
-            let mut sum = current.clone();
+            let mut sum = *current;
             for num in &last_sums {
                 if sum + num <= target_num {
                     sum += num;
@@ -276,13 +281,7 @@ fn get_dir_sizes(node_id: u32, nodes: &NodeList) -> Vec<u64> {
         }
     }
 
-    return sizes;
-}
-
-fn get_day7_input() -> Vec<String> {
-    let file = File::open("inputs/day7.txt").unwrap();
-    let lines = BufReader::new(file).lines();
-    lines.map(|s| s.unwrap()).collect()
+    sizes
 }
 
 fn create_root_node(id: u32) -> Node {
@@ -295,7 +294,7 @@ fn create_root_node(id: u32) -> Node {
     })
 }
 
-fn assess_the_situation_and_return_root_node(lines: Vec<String>) -> (u32, NodeList) {
+fn assess_the_situation_and_return_root_node(input: &str) -> Result<(u32, NodeList), ParseError> {
     let mut nodes = NodeList::default();
     let mut id_allocator = IdAllocator::new();
 
@@ -306,41 +305,40 @@ fn assess_the_situation_and_return_root_node(lines: Vec<String>) -> (u32, NodeLi
     let mut current_node_id = root_node_id;
     let mut waiting_for_output = false;
 
-    for line in lines {
-        if line.starts_with("$") {
+    for line in Span::new(input).lines() {
+        if let Ok(command) = line.strip_prefix("$") {
             // command executed
             waiting_for_output = false;
 
-            let args: Vec<&str> = line.split_whitespace().collect();
-            if args[1] == "cd" {
-                if args[2] == ".." {
+            if let Ok(dest) = command.strip_prefix("cd") {
+                if dest.as_str() == ".." {
+                    if current_node_id == root_node_id {
+                        return Err(dest.error("a directory below `/` to leave"));
+                    }
                     let node = nodes.get_node_ref(current_node_id);
                     current_node_id = node.get_parent_node_id();
-                } else if args[2] == "/" {
+                } else if dest.as_str() == "/" {
                     current_node_id = root_node_id;
                 } else {
-                    let dest_node = find_directory(&nodes, current_node_id, args[2]);
-                    current_node_id = dest_node;
+                    current_node_id = find_directory(&nodes, current_node_id, dest.as_str())
+                        .ok_or_else(|| dest.error("a directory listed by `ls`"))?;
                 }
-
-                println!(
-                    "[Current directory] {:?}",
-                    nodes.get_node_ref(current_node_id)
-                );
-            } else if args[1] == "ls" {
+            } else if command.as_str() == "ls" {
                 waiting_for_output = true;
             } else {
-                panic!("Received invalid command {}", line);
+                return Err(command.error("`cd <dir>` or `ls`"));
             }
         } else {
             // ls result
-            assert!(waiting_for_output);
+            if !waiting_for_output {
+                return Err(line.error("a `$` command"));
+            }
 
-            let args: Vec<&str> = line.split_whitespace().collect();
-            if args[0] == "dir" {
+            let (kind, name) = line.split_once(" ")?;
+            if kind.as_str() == "dir" {
                 let node = Node::Directory(DirectoryNode {
                     id: id_allocator.allocate(),
-                    name: args[1].to_string(),
+                    name: name.as_str().to_string(),
                     list: Vec::new(),
                     parent: current_node_id,
                     size: 0,
@@ -352,8 +350,8 @@ fn assess_the_situation_and_return_root_node(lines: Vec<String>) -> (u32, NodeLi
             } else {
                 let node = Node::File(FileNode {
                     id: id_allocator.allocate(),
-                    name: args[1].to_string(),
-                    size: args[0].parse::<u64>().unwrap(),
+                    name: name.as_str().to_string(),
+                    size: kind.parse::<u64>()?,
                     parent: current_node_id,
                 });
                 let parent = nodes.get_node_mut_ref(current_node_id);
@@ -370,21 +368,39 @@ fn assess_the_situation_and_return_root_node(lines: Vec<String>) -> (u32, NodeLi
             };
         }
     }
-    (root_node_id, nodes)
+    Ok((root_node_id, nodes))
 }
 
-fn find_directory(node_list: &NodeList, current: u32, name: &str) -> u32 {
+fn find_directory(node_list: &NodeList, current: u32, name: &str) -> Option<u32> {
     let current_node = node_list.get_node_ref(current);
     if let Node::Directory(dir) = current_node {
         for id in &dir.list {
             let inner_node = node_list.get_node_ref(*id);
             if let Node::Directory(dir_node) = inner_node {
                 if dir_node.name == name {
-                    return *id;
+                    return Some(*id);
                 }
             }
         }
     }
 
-    panic!("Can't reach here");
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let e =
+            assess_the_situation_and_return_root_node("$ cd /\n$ ls\ndir a\n$ cd b").unwrap_err();
+        assert_eq!((e.line, e.column), (4, 6));
+        let e = assess_the_situation_and_return_root_node("$ cd /\n$ ls\n12x b.txt").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 1));
+        let e = assess_the_situation_and_return_root_node("$ cd /\n$ rm a").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
+        let e = assess_the_situation_and_return_root_node("$ cd /\n$ cd ..").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 6));
+    }
 }
@@ -1,9 +1,22 @@
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use aoc_core::{Answer, Grid, ParseError, Solution};
 
-fn main() {
-    let trees = get_day8_input();
+pub struct Day8;
 
+impl Solution for Day8 {
+    type Parsed = Trees;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_input(input)?)
+    }
+    fn part1(trees: &Self::Parsed) -> Answer {
+        part1(trees).into()
+    }
+    fn part2(_trees: &Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+fn part1(trees: &Trees) -> i32 {
     let rows = trees.num_rows();
     let cols = trees.num_cols();
 
@@ -15,28 +28,18 @@ fn main() {
             }
         }
     }
-    println!("{}", visible);
+    visible
 }
 
-fn get_day8_input() -> Trees {
-    let file = File::open("inputs/day8.txt").unwrap();
-    let lines = BufReader::new(file).lines();
-
-    Trees {
-        grid: lines
-            .into_iter()
-            .map(|l| {
-                l.unwrap()
-                    .chars()
-                    .map(|c| c.to_digit(10).unwrap())
-                    .collect::<Vec<u32>>()
-            })
-            .collect(),
-    }
+fn parse_input(input: &str) -> Result<Trees, ParseError> {
+    Ok(Trees {
+        grid: Grid::parse(input, |c| c.to_digit(10))?,
+    })
 }
 
-struct Trees {
-    grid: Vec<Vec<u32>>,
+#[derive(Debug)]
+pub struct Trees {
+    grid: Grid<u32>,
 }
 struct Range {
     start: usize,
@@ -45,23 +48,21 @@ struct Range {
 
 impl Trees {
     fn num_rows(&self) -> usize {
-        self.grid.len()
+        self.grid.height()
     }
     fn num_cols(&self) -> usize {
-        self.grid[0].len()
+        self.grid.width()
     }
 
     fn is_visible(&self, row: usize, col: usize) -> bool {
-        return if row == 0 {
-            true
-        } else if col == 0 {
+        if row == 0 || col == 0 {
             true
         } else {
             self.is_visible_from_top(row, col)
                 || self.is_visible_from_bottom(row, col)
                 || self.is_visible_from_left(row, col)
                 || self.is_visible_from_right(row, col)
-        };
+        }
     }
     fn visible_in_range(
         &self,
@@ -71,36 +72,47 @@ impl Trees {
     ) -> bool {
         for r in row.start..row.end + 1 {
             for c in col.start..col.end + 1 {
-                if self.grid[r][c] >= height {
+                if self.grid[(r, c)] >= height {
                     return false;
                 }
             }
         }
-        return true;
+        true
     }
 
     fn is_visible_from_left(&self, row: usize, col: usize) -> bool {
-        let height = self.grid[row][col];
+        let height = self.grid[(row, col)];
         let row_range = Range { start: row, end: row };
         let col_range = Range { start: 0, end: col - 1 };
         self.visible_in_range(row_range, col_range, height)
     }
     fn is_visible_from_right(&self, row: usize, col: usize) -> bool {
-        let height = self.grid[row][col];
+        let height = self.grid[(row, col)];
         let row_range = Range { start: row, end: row };
         let col_range = Range { start: col + 1, end: self.num_cols() - 1 };
         self.visible_in_range(row_range, col_range, height)
     }
     fn is_visible_from_top(&self, row: usize, col: usize) -> bool {
-        let height = self.grid[row][col];
+        let height = self.grid[(row, col)];
         let row_range = Range { start: 0, end: row - 1 };
         let col_range = Range { start: col, end: col };
         self.visible_in_range(row_range, col_range, height)
     }
     fn is_visible_from_bottom(&self, row: usize, col: usize) -> bool {
-        let height = self.grid[row][col];
+        let height = self.grid[(row, col)];
         let row_range = Range { start: row + 1, end: self.num_rows() - 1 };
         let col_range = Range { start: col, end: col };
         self.visible_in_range(row_range, col_range, height)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse_input("30373\n25512\n653x2").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 4));
+    }
+}
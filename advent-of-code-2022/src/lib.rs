@@ -0,0 +1,13 @@
+pub const YEAR: u16 = 2022;
+pub const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
@@ -1,34 +1,59 @@
+use aoc_core::{Answer, ParseError, Solution, Span};
 use std::collections::{BTreeMap, HashMap};
 
-pub fn solution(input: String) {
-    // Part 1
-    let numbers: Vec<u32> = input
-        .lines()
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed = Vec<String>;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_lines(input)?)
+    }
+    fn part1(lines: &Self::Parsed) -> Answer {
+        Answer::or_error(part1(lines), "a line has only spelled-out digits")
+    }
+    fn part2(lines: &Self::Parsed) -> Answer {
+        part2(lines).into()
+    }
+}
+
+const DIGIT_NAMES: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Every line has to hold a calibration value, so it needs at least one
+/// digit, written either as a digit or spelled out.
+fn parse_lines(input: &str) -> Result<Vec<String>, ParseError> {
+    Span::new(input).lines_with(|line| {
+        let text = line.as_str();
+        if text.contains(|c: char| c.is_ascii_digit())
+            || DIGIT_NAMES.iter().any(|name| text.contains(name))
+        {
+            Ok(text.to_string())
+        } else {
+            Err(line.error("a digit or a spelled-out digit"))
+        }
+    })
+}
+
+/// The sum of the calibration values, or `None` if a line only has its
+/// digits spelled out.
+fn part1(lines: &[String]) -> Option<u32> {
+    lines
+        .iter()
         .map(|line| {
-            let numbers: String = line.chars().filter(|&c| !c.is_alphabetic()).collect();
-            let number = numbers[0..1].to_string() + &numbers[numbers.len() - 1..];
-            let number: u32 = number.parse().unwrap();
-            number
+            let mut digits = line.chars().filter_map(|c| c.to_digit(10));
+            let first = digits.next()?;
+            Some(first * 10 + digits.next_back().unwrap_or(first))
         })
-        .collect();
+        .sum()
+}
+
+fn part2(lines: &[String]) -> u32 {
+    let number_map: HashMap<&str, u32> = DIGIT_NAMES.into_iter().zip(1..).collect();
 
-    println!("part1: {}", numbers.iter().sum::<u32>());
-
-    // Part 2
-    let number_map: HashMap<&str, u32> = HashMap::from([
-        ("one", 1),
-        ("two", 2),
-        ("three", 3),
-        ("four", 4),
-        ("five", 5),
-        ("six", 6),
-        ("seven", 7),
-        ("eight", 8),
-        ("nine", 9),
-    ]);
-
-    let results: Vec<_> = input
-        .lines()
+    let results: Vec<_> = lines
+        .iter()
         .map(|line| {
             // find numbers in alphabet
             let result1: Vec<_> = number_map
@@ -46,7 +71,7 @@ pub fn solution(input: String) {
             let result2: Vec<_> = line
                 .chars()
                 .enumerate()
-                .filter(|&(_, c)| c.is_numeric())
+                .filter(|&(_, c)| c.is_ascii_digit())
                 .map(|(idx, c)| (idx as u32, c as u32 - '0' as u32))
                 .collect();
 
@@ -56,18 +81,36 @@ pub fn solution(input: String) {
                 numbers.insert(*a, *b);
             });
 
-            let first = numbers.iter().nth(0).unwrap().1;
+            let first = numbers.iter().next().unwrap().1;
             let last = &numbers.iter().last().unwrap().1;
 
             first.to_string() + &last.to_string()
         })
         .collect();
 
-    println!(
-        "part2: {}",
-        results
-            .iter()
-            .map(|n| n.parse::<u32>().unwrap())
-            .sum::<u32>()
-    );
+    results
+        .iter()
+        .map(|n| n.parse::<u32>().unwrap())
+        .sum::<u32>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calibration_values() {
+        let lines = parse_lines("1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet").unwrap();
+        assert_eq!(part1(&lines), Some(142));
+
+        let lines = parse_lines("two1nine\neightwothree").unwrap();
+        assert_eq!(part1(&lines), None);
+        assert_eq!(part2(&lines), 29 + 83);
+    }
+
+    #[test]
+    fn test_parse_lines_reports_line_without_digits() {
+        let e = parse_lines("1abc2\nabc").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+    }
 }
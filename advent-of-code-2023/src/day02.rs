@@ -1,34 +1,55 @@
+use aoc_core::{Answer, ParseError, Solution, Span};
+
 const MAX_RED_CUBES: u32 = 12;
 const MAX_GREEN_CUBES: u32 = 13;
 const MAX_BLUE_CUBES: u32 = 14;
 
-pub fn solution(input: String) {
-    let result = part1(&input);
-    println!("part1: {}", result);
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed = Vec<Game>;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(Span::new(input).lines_with(parse_game)?)
+    }
+    fn part1(games: &Self::Parsed) -> Answer {
+        part1(games).into()
+    }
+    fn part2(games: &Self::Parsed) -> Answer {
+        part2(games).into()
+    }
+}
 
-    let result = part2(&input);
-    println!("part2: {}", result);
+pub struct Game {
+    id: u32,
+    // every handful shown, across all the trials
+    counts: Vec<(String, u32)>,
 }
 
-// input:  2, blue
-fn to_cube_counts(count_str: &str) -> (&str, u32) {
-    let v: Vec<_> = count_str.split(' ').filter(|c| *c != "").collect();
-    let count: u32 = v[0].parse().unwrap();
-    let color = v[1];
+// input:  Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+fn parse_game(line: Span) -> Result<Game, ParseError> {
+    let (label, trials) = line.labelled()?;
+    let trials = trials.split_with(";", |trial| trial.split_with(",", to_cube_counts))?;
 
-    (color, count)
+    Ok(Game {
+        id: label.strip_prefix("Game")?.parse()?,
+        counts: trials.into_iter().flatten().collect(),
+    })
+}
+
+// input:  2 blue
+fn to_cube_counts(count_str: Span) -> Result<(String, u32), ParseError> {
+    let (count, color) = count_str.split_once(" ")?;
+    Ok((color.as_str().to_string(), count.parse()?))
 }
 
 fn is_valid(color: &str, count: u32) -> bool {
-    return if color == "blue" && count <= MAX_BLUE_CUBES {
-        true
-    } else if color == "green" && count <= MAX_GREEN_CUBES {
-        true
-    } else if color == "red" && count <= MAX_RED_CUBES {
-        true
-    } else {
-        false
-    };
+    match color {
+        "blue" => count <= MAX_BLUE_CUBES,
+        "green" => count <= MAX_GREEN_CUBES,
+        "red" => count <= MAX_RED_CUBES,
+        _ => false,
+    }
 }
 
 #[derive(Default)]
@@ -51,64 +72,28 @@ impl Cubes {
     }
 }
 
-fn part1(input: &String) -> u32 {
-    let result: Vec<u32> = input
-        .lines()
-        .map(|l| {
-            let split: Vec<&str> = l.split(':').collect();
-            let game_id = split[0].split(' ').last().unwrap();
-            let trials: Vec<_> = split[1].split(';').collect();
-
-            let result: Vec<u32> = trials
+fn part1(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .filter(|game| {
+            game.counts
                 .iter()
-                .map(|trial| {
-                    trial
-                        .split(',')
-                        .collect::<Vec<_>>()
-                        .iter()
-                        .map(|count_str| {
-                            let (color, count) = to_cube_counts(count_str);
-                            if is_valid(color, count) {
-                                0
-                            } else {
-                                1
-                            }
-                        })
-                        .sum()
-                })
-                .collect();
-            let result: u32 = result.iter().sum();
-            // result is number of impossible combination
-
-            if result == 0 {
-                game_id.parse::<u32>().unwrap()
-            } else {
-                0
-            }
+                .all(|(color, count)| is_valid(color, *count))
         })
-        .collect();
-
-    result.iter().sum()
+        .map(|game| game.id)
+        .sum()
 }
 
-fn part2(input: &String) -> u32 {
-    let result: Vec<u32> = input
-        .lines()
-        .map(|l| {
-            let split: Vec<&str> = l.split(':').collect();
-            let trials: Vec<_> = split[1].split(';').collect();
-
+fn part2(games: &[Game]) -> u32 {
+    games
+        .iter()
+        .map(|game| {
             let mut cube_counts = Cubes::default();
-            trials.iter().for_each(|t| {
-                let counts: Vec<&str> = t.split(',').collect();
-                counts.iter().for_each(|c| {
-                    let (color, count) = to_cube_counts(*c);
-                    cube_counts.update(color, count);
-                });
-            });
+            game.counts
+                .iter()
+                .for_each(|(color, count)| cube_counts.update(color, *count));
 
             cube_counts.get_result()
         })
-        .collect();
-    result.iter().sum()
+        .sum()
 }
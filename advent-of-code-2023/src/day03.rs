@@ -1,22 +1,19 @@
-const TEST_INPUT: &str = "467..114..
-...*......
-..35..633.
-......#...
-617*......
-.....+.58.
-..592.....
-......755.
-...$.*....
-.664.598..";
-
-pub fn solution(input: String) {
-    let (numbers, symbols) = get(&input);
-
-    let result = part1(&numbers);
-    println!("part1: {}", result.iter().sum::<u32>());
-
-    let result = part2(&symbols);
-    println!("part2: {:?}", result.iter().sum::<u32>());
+use aoc_core::{Answer, ParseError, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = (Vec<Number>, Vec<Symbol>);
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(get(input)?)
+    }
+    fn part1((numbers, _): &Self::Parsed) -> Answer {
+        part1(numbers).iter().sum::<u32>().into()
+    }
+    fn part2((_, symbols): &Self::Parsed) -> Answer {
+        part2(symbols).iter().sum::<u32>().into()
+    }
 }
 
 fn prev_loc(line: usize) -> usize {
@@ -34,7 +31,7 @@ fn between(target: usize, min: usize, max: usize) -> bool {
 }
 
 #[derive(Default, Debug)]
-struct Number {
+pub struct Number {
     value: String,
     line: usize,
     start: usize,
@@ -60,7 +57,7 @@ impl Number {
         self.value.push(c);
     }
 
-    fn update_adjacent(&mut self, symbols: &mut Vec<Symbol>) {
+    fn update_adjacent(&mut self, symbols: &mut [Symbol]) {
         for s in symbols.iter_mut() {
             if between(s.loc.0, prev_loc(self.line), next_loc(self.line))
                 && between(s.loc.1, prev_loc(self.start), next_loc(self.end))
@@ -72,7 +69,7 @@ impl Number {
     }
 
     fn has_adjacent(&self) -> bool {
-        self.adjacent_symbols.len() != 0
+        !self.adjacent_symbols.is_empty()
     }
     fn value(&self) -> u32 {
         self.value.parse().unwrap()
@@ -80,19 +77,27 @@ impl Number {
 }
 
 #[derive(Debug)]
-struct Symbol {
+pub struct Symbol {
     value: char,
     loc: (usize, usize),
     adjacent_numbers: Vec<u32>,
 }
 
-fn get(input: &String) -> (Vec<Number>, Vec<Symbol>) {
+/// Every number and symbol of the schematic, with the numbers linked to
+/// the symbols next to them. The schematic has to be ASCII, so that columns
+/// are byte offsets, and its numbers have to fit in a `u32`.
+fn get(input: &str) -> Result<(Vec<Number>, Vec<Symbol>), ParseError> {
+    if let Some((i, c)) = input.char_indices().find(|(_, c)| !c.is_ascii()) {
+        let text = &input[i..i + c.len_utf8()];
+        return Err(ParseError::at(input, text, "an ASCII character"));
+    }
+
     let mut symbols = Vec::new();
     let mut numbers: Vec<Number> = Vec::new();
 
     input.lines().enumerate().for_each(|(idx, value)| {
         value.chars().enumerate().for_each(|(c_idx, c)| {
-            if c.is_numeric() {
+            if c.is_ascii_digit() {
                 if numbers.last().is_some() && numbers.last().unwrap().can_be_added((idx, c_idx)) {
                     numbers.last_mut().unwrap().add(c, (idx, c_idx));
                 } else {
@@ -108,24 +113,45 @@ fn get(input: &String) -> (Vec<Number>, Vec<Symbol>) {
         })
     });
 
+    let lines: Vec<&str> = input.lines().collect();
+    for num in &numbers {
+        if num.value.parse::<u32>().is_err() {
+            let text = &lines[num.line][num.start..=num.end];
+            return Err(ParseError::at(input, text, "a number that fits in a u32"));
+        }
+    }
+
     numbers.iter_mut().for_each(|num| {
         num.update_adjacent(&mut symbols);
     });
 
-    (numbers, symbols)
+    Ok((numbers, symbols))
 }
 
-fn part1(numbers: &Vec<Number>) -> Vec<u32> {
+fn part1(numbers: &[Number]) -> Vec<u32> {
     numbers
         .iter()
         .map(|num| if num.has_adjacent() { num.value() } else { 0 })
         .collect()
 }
 
-fn part2(symbols: &Vec<Symbol>) -> Vec<u32> {
+fn part2(symbols: &[Symbol]) -> Vec<u32> {
     symbols
         .iter()
         .filter(|s| s.value == '*' && s.adjacent_numbers.len() == 2)
-        .map(|g| g.adjacent_numbers.iter().fold(1, |acc, &n| acc * n))
+        .map(|g| g.adjacent_numbers.iter().product::<u32>())
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let e = get("467..114..\n...*..€...").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 7));
+        let e = get("467..114..\n..*.99999999999").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 5));
+    }
+}
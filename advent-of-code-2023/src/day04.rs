@@ -1,59 +1,47 @@
-const TEST_INPUT: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
-Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
-Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
-Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
-Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
-Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+use aoc_core::{Answer, Memo, ParseError, Solution, Span};
 
-pub fn solution(input: String) {
-    let mut cards = get(&input);
+pub struct Day04;
 
-    let result = part1(&cards);
-    println!("part1: {:?}", result.iter().sum::<i32>());
+impl Solution for Day04 {
+    type Parsed = Vec<Card>;
 
-    let result = part2(&mut cards);
-    println!("part2: {:?}", result.iter().sum::<i32>());
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(get(input)?)
+    }
+    fn part1(cards: &Self::Parsed) -> Answer {
+        part1(cards).iter().sum::<i32>().into()
+    }
+    fn part2(cards: &Self::Parsed) -> Answer {
+        part2(cards).iter().sum::<i32>().into()
+    }
 }
 
-struct Card {
+#[allow(dead_code)]
+#[derive(Clone)]
+pub struct Card {
     winning: Vec<i32>,
     numbers: Vec<i32>,
     matches: i32,
-    copies: i32,
 }
-fn get(input: &String) -> Vec<Card> {
-    input
-        .lines()
-        .map(|l| {
-            let split: Vec<_> = l.split(":").collect();
-            let only_numbers: Vec<_> = split[1].split("|").collect();
+fn get(input: &str) -> Result<Vec<Card>, ParseError> {
+    Span::new(input).lines_with(|l| {
+        let (_, only_numbers) = l.labelled()?;
+        let (winning, numbers): (Vec<i32>, Vec<i32>) =
+            only_numbers.pair_with("|", |n| n.list(" "), |n| n.list(" "))?;
 
-            let winning: Vec<_> = only_numbers[0]
-                .split(" ")
-                .filter(|n| *n != "")
-                .map(|n| n.parse::<i32>().unwrap())
-                .collect();
-            let numbers: Vec<_> = only_numbers[1]
-                .split(" ")
-                .filter(|n| *n != "")
-                .map(|n| n.parse::<i32>().unwrap())
-                .collect();
+        let matches: i32 = numbers
+            .iter()
+            .map(|n| if winning.contains(n) { 1 } else { 0 })
+            .sum();
 
-            let matches: i32 = numbers
-                .iter()
-                .map(|n| if winning.contains(n) { 1 } else { 0 })
-                .sum();
-
-            Card {
-                winning,
-                numbers,
-                matches,
-                copies: 1,
-            }
+        Ok(Card {
+            winning,
+            numbers,
+            matches,
         })
-        .collect()
+    })
 }
-fn part1(cards: &Vec<Card>) -> Vec<i32> {
+fn part1(cards: &[Card]) -> Vec<i32> {
     cards
         .iter()
         .map(|c| {
@@ -67,18 +55,18 @@ fn part1(cards: &Vec<Card>) -> Vec<i32> {
         .collect()
 }
 
-fn part2(cards: &mut Vec<Card>) -> Vec<i32> {
-    for idx in 0..cards.len() {
-        let card = &cards[idx];
-        let num_cards_to_update = card.matches;
-        let num_copies = card.copies;
-
-        for i in 1..=num_cards_to_update as usize {
-            if idx + 1 < cards.len() {
-                cards[idx + i].copies += num_copies;
-            }
-        }
-    }
-
-    cards.iter().map(|c| c.copies).collect()
+/// How many cards a single instance of each card ends up as, itself
+/// included. Every copy of a card wins the same cards, so this only depends
+/// on which card it is.
+fn part2(cards: &[Card]) -> Vec<i32> {
+    let mut memo = Memo::new();
+    (0..cards.len())
+        .map(|idx| {
+            memo.get(idx, |rec, &idx| {
+                let won =
+                    (idx + 1..=idx + cards[idx].matches as usize).filter(|&i| i < cards.len());
+                1 + won.map(|i| rec.call(i)).sum::<i32>()
+            })
+        })
+        .collect()
 }
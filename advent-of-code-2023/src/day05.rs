@@ -1,467 +1,144 @@
-use std::fmt::{Debug, Formatter};
+use aoc_core::{Answer, Interval, IntervalSet, ParseError, PiecewiseMap, Solution, Span};
 
-pub fn solution(input: String) {
-    let (seeds, maps) = get(&input);
+pub struct Day05;
 
-    let result = part1(&seeds, &maps);
-    println!("part1: {}", result);
+impl Solution for Day05 {
+    type Parsed = (Vec<u128>, PiecewiseMap<u128>);
 
-    let result = part2(&seeds, &maps);
-    println!("part2: {:?}", result);
-}
-
-#[derive(Clone, PartialOrd, Ord, PartialEq, Eq)]
-struct Range {
-    start: u128,
-    end: u128,
-}
-impl Debug for Range {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} <= value <= {}", self.start, self.end)
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        get(input)
     }
-}
-impl Range {
-    pub fn new(start: u128, end: u128) -> Self {
-        Self { start, end }
+    fn part1((seeds, almanac): &Self::Parsed) -> Answer {
+        part1(seeds, almanac).into()
     }
-    pub fn in_range(&self, number: u128) -> bool {
-        self.start <= number && number <= self.end
+    fn part2((seeds, almanac): &Self::Parsed) -> Answer {
+        part2(seeds, almanac).into()
     }
 }
 
-#[derive(Debug, Ord, PartialOrd, PartialEq, Eq)]
-struct RangeMap {
-    src_range: Range,
-    dest_start: u128,
-}
-impl RangeMap {
-    pub fn map(&self, src: u128) -> Option<u128> {
-        if self.src_range.in_range(src) {
-            Some(self.dest_start + (src - self.src_range.start))
-        } else {
-            None
-        }
-    }
-}
-
-#[derive(Debug, Default, PartialEq)]
-struct Map {
-    range_maps: Vec<RangeMap>, // src, dest, len
-}
-
-impl Map {
-    pub fn map(&self, seed: u128) -> u128 {
-        for range_map in &self.range_maps {
-            if let Some(result) = range_map.map(seed) {
-                return result;
-            }
-        }
-
-        panic!("should not happen");
-    }
-
-    pub fn map_range(&self, seed: &Range) -> Vec<Range> {
-        let split = self.split_range(seed);
-
-        split
-            .iter()
-            .map(|src| Range::new(self.map(src.start), self.map(src.end)))
-            .collect()
-    }
-
-    fn split_range(&self, seed: &Range) -> Vec<Range> {
-        let mut ranges = Vec::new();
-
-        let mut next_find = seed.start;
-        // range_maps is sorted already..
-        for range_map in &self.range_maps {
-            if seed.in_range(next_find) == false {
-                break;
-            }
-
-            if range_map.src_range.in_range(next_find) {
-                let current_end = if range_map.src_range.end > seed.end {
-                    seed.end
-                } else {
-                    range_map.src_range.end
-                };
-
-                ranges.push(Range::new(next_find, current_end));
-                next_find = current_end + 1;
-            }
-        }
-
-        ranges
-    }
-}
-
-fn get(input: &str) -> (Vec<u128>, Vec<Map>) {
-    let (seeds, mut maps) = get_input(input);
-    fill_up_maps(&mut maps);
-
-    (seeds, maps)
-}
-
-fn get_input(input: &str) -> (Vec<u128>, Vec<Map>) {
-    let mut seeds = Vec::new();
-    let mut maps = Vec::new();
-
-    input.lines().for_each(|line| {
-        if line.starts_with("seeds:") {
-            let numbers = line.split(":").collect::<Vec<_>>()[1];
-            seeds = numbers
-                .split(" ")
-                .filter(|n| *n != " " && *n != "")
-                .map(|n| n.parse::<u128>().unwrap())
-                .collect();
-        } else if line.contains("map:") {
-            maps.push(Map {
-                range_maps: Vec::new(),
-            });
-        } else if line.is_empty() {
-            // skip
-        } else {
-            // numbers
-            let numbers: Vec<u128> = line
-                .split(" ")
-                .map(|n| n.parse::<u128>().unwrap())
-                .collect();
-            assert_eq!(numbers.len(), 3);
-
-            let dest_range_start = numbers[0];
-            let src_range_start = numbers[1];
-            let num_values = numbers[2];
-
-            let map = maps.last_mut().unwrap();
-            map.range_maps.push(RangeMap {
-                src_range: Range::new(src_range_start, src_range_start + num_values - 1),
-                dest_start: dest_range_start,
-            });
-        }
-    });
+/// The seeds and the single map from seed to location that the almanac's
+/// chain of maps flattens into.
+fn get(input: &str) -> aoc_core::Result<(Vec<u128>, PiecewiseMap<u128>)> {
+    let (seeds, maps) = get_input(input)?;
+    let almanac = maps
+        .iter()
+        .fold(PiecewiseMap::new(), |chain, map| chain.then(map));
 
-    (seeds, maps)
+    Ok((seeds, almanac))
 }
 
-fn fill_up_maps(maps: &mut Vec<Map>) {
-    // fill up the remaining ranges
-    maps.iter_mut().for_each(|m| {
-        m.range_maps.sort();
-
-        let mut last_start = 0;
-        let mut new_ranges = Vec::new();
-        for range in &mut m.range_maps {
-            if last_start < range.src_range.start {
-                new_ranges.push(RangeMap {
-                    src_range: Range::new(last_start, range.src_range.start - 1),
-                    dest_start: last_start,
-                });
-
-                last_start = range.src_range.end + 1;
-            } else if last_start == range.src_range.start {
-                last_start = range.src_range.end + 1;
-            }
-        }
-
-        new_ranges.push(RangeMap {
-            src_range: Range {
-                start: last_start,
-                end: u128::MAX - 1,
-            },
-            dest_start: last_start,
-        });
-        m.range_maps.append(&mut new_ranges);
+fn get_input(input: &str) -> Result<(Vec<u128>, Vec<PiecewiseMap<u128>>), ParseError> {
+    let mut blocks = Span::new(input).blocks();
+    let Some(seeds) = blocks.next() else {
+        return Err(Span::new(input).error("a `seeds:` line"));
+    };
+    let seeds = seeds.strip_prefix("seeds:")?.list(" ")?;
+    let maps = blocks.map(get_map).collect::<Result<_, _>>()?;
 
-        m.range_maps.sort();
-    });
+    Ok((seeds, maps))
 }
 
-fn find_location(seed: u128, maps: &Vec<Map>) -> u128 {
-    maps.iter().fold(seed, |acc, m| m.map(acc))
-}
-
-fn part1(seeds: &Vec<u128>, maps: &Vec<Map>) -> u128 {
-    let locations: Vec<_> = seeds.iter().map(|n| find_location(*n, &maps)).collect();
-    *locations.iter().min().unwrap()
-}
+fn get_map(block: Span) -> Result<PiecewiseMap<u128>, ParseError> {
+    let mut lines = block.lines();
+    let header = lines.next().unwrap_or(block);
+    if !header.as_str().ends_with("map:") {
+        return Err(header.error("a `... map:` header"));
+    }
 
-fn get_seed_range(seeds: &Vec<u128>) -> Vec<Range> {
-    let mut range_seeds = Vec::new();
-    for (idx, value) in seeds.iter().enumerate() {
-        if idx % 2 == 0 {
-            range_seeds.push(Range {
-                start: *value,
-                end: 0u128, // to be updated
-            })
-        } else {
-            let last = range_seeds.last_mut().unwrap();
-            last.end = last.start + *value - 1;
-        }
+    let mut map = PiecewiseMap::new();
+    for line in lines {
+        let numbers: Vec<u128> = line.list(" ")?;
+        let [dest_range_start, src_range_start, num_values] = numbers[..] else {
+            return Err(line.error("3 numbers: destination start, source start and length"));
+        };
+        map.insert(
+            src_range_start..src_range_start + num_values,
+            dest_range_start,
+        );
     }
 
-    range_seeds
+    Ok(map)
 }
 
-// timeout version
-#[allow(dead_code)]
-fn part2_timeout(seeds: &Vec<u128>, maps: &Vec<Map>) -> u128 {
-    let range_seeds = get_seed_range(seeds);
-
-    *range_seeds
-        .iter()
-        .map(|seed| {
-            let mut locations = Vec::new();
-            for val in seed.start..seed.start + seed.end {
-                locations.push(find_location(val, &maps));
-            }
-            println!("locations: {:?}", locations);
-            *locations.iter().min().unwrap()
-        })
-        .collect::<Vec<_>>()
-        .iter()
-        .min()
-        .unwrap()
+fn part1(seeds: &[u128], almanac: &PiecewiseMap<u128>) -> u128 {
+    seeds.iter().map(|&n| almanac.apply(n)).min().unwrap()
 }
 
-fn find_location_in_range(seed: &Range, maps: &Vec<Map>) -> u128 {
-    let mut input = vec![(*seed).clone()];
-
-    for map in maps {
-        let result = input
-            .iter()
-            .map(|i| map.map_range(i))
-            .collect::<Vec<Vec<_>>>()
-            .into_iter()
-            .flatten()
-            .collect();
-
-        input = result;
-    }
-
-    input.iter().map(|n| n.start).min().unwrap()
+/// The seed numbers read as `start length` pairs.
+fn get_seed_range(seeds: &[u128]) -> IntervalSet<u128> {
+    seeds
+        .chunks(2)
+        .map(|pair| Interval::new(pair[0], pair[0] + pair[1]))
+        .collect()
 }
 
-fn part2(seeds: &Vec<u128>, maps: &Vec<Map>) -> u128 {
-    let range_seeds = get_seed_range(&seeds);
-    range_seeds
-        .iter()
-        .map(|seed| find_location_in_range(seed, maps))
-        .min()
-        .unwrap()
+fn part2(seeds: &[u128], almanac: &PiecewiseMap<u128>) -> u128 {
+    let locations = almanac.apply_set(&get_seed_range(seeds));
+    let nearest = locations.iter().next().unwrap();
+    nearest.start
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = "seeds: 79 14 55 13
-
-seed-to-soil map:
-50 98 2
-52 50 48
-
-soil-to-fertilizer map:
-0 15 37
-37 52 2
-39 0 15
-
-fertilizer-to-water map:
-49 53 8
-0 11 42
-42 0 7
-57 7 4
-
-water-to-light map:
-88 18 7
-18 25 70
-
-light-to-temperature map:
-45 77 23
-81 45 19
-68 64 13
-
-temperature-to-humidity map:
-0 69 1
-1 0 69
-
-humidity-to-location map:
-60 56 37
-56 93 4";
-
     #[test]
-    fn test_part1_with_test_input() {
-        let (seeds, mut maps) = get_input(TEST_INPUT);
+    fn test_example_maps() {
+        let input = crate::read_example(5, "1").unwrap();
+        let (seeds, maps) = get_input(&input).unwrap();
         assert_eq!(seeds, vec![79, 14, 55, 13]);
         assert_eq!(maps.len(), 7);
         assert_eq!(
-            maps[0],
-            Map {
-                range_maps: vec![
-                    RangeMap {
-                        src_range: Range::new(98, 99),
-                        dest_start: 50,
-                    },
-                    RangeMap {
-                        src_range: Range::new(50, 97),
-                        dest_start: 52,
-                    },
-                ],
-            }
+            [79, 14, 55, 13].map(|seed| maps[0].apply(seed)),
+            [81, 14, 57, 13]
         );
 
-        fill_up_maps(&mut maps);
-        assert_eq!(
-            maps[0],
-            Map {
-                range_maps: vec![
-                    RangeMap {
-                        src_range: Range::new(0, 49),
-                        dest_start: 0,
-                    },
-                    RangeMap {
-                        src_range: Range::new(50, 97),
-                        dest_start: 52,
-                    },
-                    RangeMap {
-                        src_range: Range::new(98, 99),
-                        dest_start: 50,
-                    },
-                    RangeMap {
-                        src_range: Range::new(100, u128::MAX - 1),
-                        dest_start: 100,
-                    }
-                ]
-            }
-        );
-
-        let result = part1(&seeds, &maps);
-        assert_eq!(result, 35);
+        let (_, almanac) = get(&input).unwrap();
+        assert_eq!(almanac.apply(79), 82);
     }
 
     #[test]
     fn test_part1() {
-        let input = crate::read_from_file("inputs/day05.txt");
-        let (seeds, maps) = get(&input);
-        let result = part1(&seeds, &maps);
+        let input = crate::read_input(5).unwrap();
+        let (seeds, almanac) = get(&input).unwrap();
+        let result = part1(&seeds, &almanac);
         assert_eq!(result, 165788812);
     }
 
-    #[test]
-    fn test_part2_with_test_input() {
-        let (seeds, maps) = get(TEST_INPUT);
-        let result = part2(&seeds, &maps);
-        assert_eq!(result, 46);
-    }
-
     #[test]
     fn test_part2() {
-        let input = crate::read_from_file("inputs/day05.txt");
-        let (seeds, maps) = get(&input);
-        let result = part2(&seeds, &maps);
+        let input = crate::read_input(5).unwrap();
+        let (seeds, almanac) = get(&input).unwrap();
+        let result = part2(&seeds, &almanac);
         assert_eq!(result, 1928058);
     }
 
     #[test]
-    fn test_range_map() {
-        let range_map = RangeMap {
-            src_range: Range::new(10, 200),
-            dest_start: 30,
-        };
-
-        assert_eq!(range_map.map(15), Some(35));
-        assert_eq!(range_map.map(1), None);
-    }
-
-    #[test]
-    fn test_range_maps() {
-        let mut map = Map::default();
-        map.range_maps.push(RangeMap {
-            src_range: Range::new(0, 20),
-            dest_start: 10,
-        });
-        map.range_maps.push(RangeMap {
-            src_range: Range::new(20, 100),
-            dest_start: 200,
-        });
-        map.range_maps.push(RangeMap {
-            src_range: Range::new(100, u128::MAX),
-            dest_start: 100,
-        });
-
-        assert_eq!(map.map(10), 20);
-        assert_eq!(map.map(30), 210);
-        assert_eq!(map.map(400), 400);
+    fn test_get_input_reports_bad_number() {
+        let input = "seeds: 79 14\n\nseed-to-soil map:\n50 9x8 2";
+        let e = get_input(input).unwrap_err();
+        assert_eq!((e.line, e.column), (4, 4));
+        assert_eq!(e.text, "9x8");
     }
 
     #[test]
     fn test_get_seed_range() {
         let seeds = vec![79, 14, 55, 13];
-        let ranges = get_seed_range(&seeds);
+        let ranges: Vec<_> = get_seed_range(&seeds).iter().copied().collect();
 
-        let expected = vec![Range::new(79, 79 + 14 - 1), Range::new(55, 55 + 13 - 1)];
+        let expected = vec![Interval::new(55, 55 + 13), Interval::new(79, 79 + 14)];
 
         assert_eq!(ranges, expected);
     }
 
     #[test]
-    fn test_range_split() {
-        let mut map = Map::default();
-        map.range_maps.push(RangeMap {
-            src_range: Range::new(0, 19),
-            dest_start: 10,
-        });
-        map.range_maps.push(RangeMap {
-            src_range: Range::new(20, 99),
-            dest_start: 200,
-        });
-        map.range_maps.push(RangeMap {
-            src_range: Range::new(100, u128::MAX),
-            dest_start: 100,
-        });
-
-        let src_range = Range::new(10, 300);
-        let expected = vec![Range::new(10, 19), Range::new(20, 99), Range::new(100, 300)];
-        assert_eq!(map.split_range(&src_range), expected);
-    }
-
-    #[test]
-    fn test_find_location_in_range() {
-        let seed = Range::new(10, 600);
-        let map = Map {
-            range_maps: vec![
-                RangeMap {
-                    src_range: Range::new(0, 29),
-                    dest_start: 0,
-                },
-                RangeMap {
-                    src_range: Range::new(30, 59),
-                    dest_start: 100,
-                },
-                RangeMap {
-                    src_range: Range::new(60, 499),
-                    dest_start: 20,
-                },
-                RangeMap {
-                    src_range: Range::new(500, u128::MAX - 1),
-                    dest_start: 500,
-                },
-            ],
-        };
-
-        let expected = vec![
-            Range::new(10, 29),
-            Range::new(30, 59),
-            Range::new(60, 499),
-            Range::new(500, 600),
-        ];
-        assert_eq!(map.split_range(&seed), expected);
-
-        let expected = vec![
-            Range::new(10, 29),
-            Range::new(100, 100 + (59 - 30)),
-            Range::new(20, 20 + (499 - 60)),
-            Range::new(500, 600),
-        ];
-        assert_eq!(map.map_range(&seed), expected);
+    fn test_almanac_matches_map_chain() {
+        let input = crate::read_example(5, "1").unwrap();
+        let (_, maps) = get_input(&input).unwrap();
+        let (_, almanac) = get(&input).unwrap();
+        for seed in 0..120 {
+            let location = maps.iter().fold(seed, |value, map| map.apply(value));
+            assert_eq!(almanac.apply(seed), location);
+        }
     }
 }
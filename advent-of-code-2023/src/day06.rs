@@ -1,15 +1,24 @@
+use aoc_core::{Answer, ParseError, Solution, Span};
 use std::collections::HashMap;
 
-pub fn solution(input: String) {
-    let result = part1(&input);
-    println!("part1: {}", result);
+pub struct Day06;
 
-    let result = part2(&input);
-    println!("part2: {}", result);
+impl Solution for Day06 {
+    type Parsed = (Vec<Race>, Vec<Race>);
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok((get_part1_races(input)?, get_part2_races(input)?))
+    }
+    fn part1((races, _): &Self::Parsed) -> Answer {
+        part1(races).into()
+    }
+    fn part2((_, races): &Self::Parsed) -> Answer {
+        part2(races).into()
+    }
 }
 
-#[derive(Debug, PartialEq)]
-struct Race {
+#[derive(Debug, PartialEq, Clone)]
+pub struct Race {
     time: u64,
     distance: u64,
     possibilities: HashMap<u64, u64>,
@@ -36,34 +45,31 @@ impl Race {
     }
 }
 
-fn get_part1_races(lines: &str) -> Vec<Race> {
+fn get_part1_races(lines: &str) -> Result<Vec<Race>, ParseError> {
     let mut races = Vec::new();
 
-    lines.lines().for_each(|l| {
-        let numbers: Vec<_> = l
-            .split(" ")
-            .filter(|&c| c != "" && c.chars().all(|c| c.is_numeric()))
-            .map(|c| c.parse::<u64>().unwrap())
-            .collect();
+    for l in Span::new(lines).lines() {
+        let (label, numbers) = l.labelled()?;
+        let numbers: Vec<u64> = numbers.list(" ")?;
 
         for idx in 0..numbers.len() {
-            if l.starts_with("Time:") {
+            if label.as_str() == "Time" {
                 races.push(Race::new(numbers[idx]));
-            } else if l.starts_with("Distance:") {
+            } else if label.as_str() == "Distance" {
                 races[idx].distance = numbers[idx];
             }
         }
-    });
+    }
 
-    races
+    Ok(races)
 }
 
-fn part1(input: &str) -> u64 {
-    let mut races = get_part1_races(&input);
+fn part1(races: &[Race]) -> u64 {
+    let mut races = races.to_vec();
     get_result(&mut races)
 }
 
-fn get_result(races: &mut Vec<Race>) -> u64 {
+fn get_result(races: &mut [Race]) -> u64 {
     let result: Vec<_> = races
         .iter_mut()
         .map(|r| {
@@ -72,29 +78,32 @@ fn get_result(races: &mut Vec<Race>) -> u64 {
         })
         .collect();
 
-    result.iter().fold(1, |acc, &v| acc * v)
+    result.iter().product()
 }
 
-fn get_part2_races(lines: &str) -> Vec<Race> {
+fn get_part2_races(lines: &str) -> Result<Vec<Race>, ParseError> {
     let mut races = Vec::new();
 
-    lines.lines().for_each(|l| {
-        let numbers = l.split(":").collect::<Vec<_>>()[1];
-        let numbers = numbers.trim().replace(" ", "");
-        let number = numbers.parse::<u64>().unwrap();
+    for l in Span::new(lines).lines() {
+        let (label, numbers) = l.labelled()?;
+        // the spaces between the numbers are bad kerning
+        let number: String = numbers.split(" ").map(|n| n.as_str()).collect();
+        let number = number
+            .parse::<u64>()
+            .map_err(|_| numbers.error("a number split by spaces"))?;
 
-        if l.starts_with("Time:") {
+        if label.as_str() == "Time" {
             races.push(Race::new(number));
-        } else if l.starts_with("Distance:") {
+        } else if label.as_str() == "Distance" {
             races.last_mut().unwrap().distance = number;
         }
-    });
+    }
 
-    races
+    Ok(races)
 }
 
-fn part2(input: &str) -> u64 {
-    let mut races = get_part2_races(&input);
+fn part2(races: &[Race]) -> u64 {
+    let mut races = races.to_vec();
     // slow.. but can get the answer
     get_result(&mut races)
 }
@@ -103,12 +112,9 @@ fn part2(input: &str) -> u64 {
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = "Time:      7  15   30
-Distance:  9  40  200";
-
     #[test]
     fn test_part1_parsing() {
-        let races = get_part1_races(TEST_INPUT);
+        let races = get_part1_races(&crate::read_example(6, "1").unwrap()).unwrap();
 
         let expected = vec![
             Race {
@@ -132,7 +138,7 @@ Distance:  9  40  200";
 
     #[test]
     fn test_part2_parsing() {
-        let races = get_part2_races(TEST_INPUT);
+        let races = get_part2_races(&crate::read_example(6, "1").unwrap()).unwrap();
 
         let expected = vec![Race {
             time: 71530,
@@ -160,16 +166,4 @@ Distance:  9  40  200";
         assert_eq!(race.possibilities[&6], 6);
         assert_eq!(race.possibilities[&7], 0);
     }
-
-    #[test]
-    fn test_part1_with_test_input() {
-        let result = part1(TEST_INPUT);
-        assert_eq!(result, 288);
-    }
-
-    #[test]
-    fn test_part2_with_test_input() {
-        let result = part2(TEST_INPUT);
-        assert_eq!(result, 71503);
-    }
 }
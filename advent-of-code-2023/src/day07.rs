@@ -1,13 +1,42 @@
+use aoc_core::{Answer, ParseError, Solution, Span};
 use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
 
-pub fn solution(input: String) {
-    let result = part1(&input);
-    println!("part1: {}", result);
+pub struct Day07;
 
-    let result = part2(&input);
-    println!("part2: {}", result);
+impl Solution for Day07 {
+    type Parsed = Vec<Deal>;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(Span::new(input).lines_with(parse_deal)?)
+    }
+    fn part1(deals: &Self::Parsed) -> Answer {
+        part1(deals).into()
+    }
+    fn part2(deals: &Self::Parsed) -> Answer {
+        part2(deals).into()
+    }
+}
+
+/// A hand of cards and what was bid on it.
+#[derive(Debug)]
+pub struct Deal {
+    cards: String,
+    bid: u64,
+}
+
+// input:  32T3K 765
+fn parse_deal(line: Span) -> Result<Deal, ParseError> {
+    let (cards, bid) = line.split_once(" ")?;
+    let is_card = |c| CARD_RANK.contains(&c);
+    if cards.as_str().len() != 5 || !cards.as_str().chars().all(is_card) {
+        return Err(cards.error("five cards out of AKQJT98765432"));
+    }
+    Ok(Deal {
+        cards: cards.as_str().to_string(),
+        bid: bid.parse()?,
+    })
 }
 
 #[derive(PartialEq, Debug, PartialOrd, Ord, Eq)]
@@ -63,7 +92,7 @@ impl PartialOrd<Self> for Hand {
 
 impl Ord for Hand {
     fn cmp(&self, other: &Self) -> Ordering {
-        return if self.hand_type != other.hand_type {
+        if self.hand_type != other.hand_type {
             self.hand_type.cmp(&other.hand_type)
         } else {
             for (idx, c) in self.cards.chars().enumerate() {
@@ -78,7 +107,7 @@ impl Ord for Hand {
             }
 
             Ordering::Equal
-        };
+        }
     }
 }
 
@@ -92,7 +121,7 @@ fn cards_to_counts(cards: &str) -> HashMap<char, i32> {
     let mut kinds = HashMap::new();
     cards.chars().for_each(|c| {
         if let Some(value) = kinds.get_mut(&c) {
-            *value = *value + 1;
+            *value += 1;
         } else {
             kinds.insert(c, 1);
         }
@@ -101,8 +130,8 @@ fn cards_to_counts(cards: &str) -> HashMap<char, i32> {
     kinds
 }
 
-fn values_to_type(values: &Vec<i32>) -> HandType {
-    return if values.len() == 0 {
+fn values_to_type(values: &[i32]) -> HandType {
+    if values.is_empty() {
         HandType::None
     } else if values.contains(&5) {
         HandType::FiveKind
@@ -120,7 +149,7 @@ fn values_to_type(values: &Vec<i32>) -> HandType {
         HandType::HighCard
     } else {
         panic!("can't reach here");
-    };
+    }
 }
 
 fn to_hand_type(cards: &str) -> HandType {
@@ -132,10 +161,10 @@ fn to_hand_type(cards: &str) -> HandType {
 fn to_hand_type_with_joker(cards: &str) -> HandType {
     let kinds = cards_to_counts(cards);
 
-    let mut values: Vec<_> = kinds.iter().filter(|v| *v.0 != 'J').map(|v| *v.1).collect();
-    let current_type = values_to_type(&mut values);
+    let values: Vec<_> = kinds.iter().filter(|v| *v.0 != 'J').map(|v| *v.1).collect();
+    let current_type = values_to_type(&values);
 
-    return if let Some(&num_jokers) = kinds.get(&'J') {
+    if let Some(&num_jokers) = kinds.get(&'J') {
         assert!(num_jokers > 0);
         let new_type = match current_type {
             HandType::FiveKind => {
@@ -188,61 +217,47 @@ fn to_hand_type_with_joker(cards: &str) -> HandType {
         new_type
     } else {
         current_type
-    };
+    }
 }
 
-fn part1(input: &str) -> u64 {
-    let mut hands: Vec<_> = input
-        .lines()
-        .map(|line| {
-            let values: Vec<_> = line.split(" ").collect();
-            assert_eq!(values.len(), 2);
-
-            Hand {
-                cards: values[0].to_string(),
-                bid: values[1].parse::<u64>().unwrap(),
-                hand_type: to_hand_type(values[0]),
-                rank: CARD_RANK,
-            }
+fn part1(deals: &[Deal]) -> u64 {
+    let mut hands: Vec<_> = deals
+        .iter()
+        .map(|deal| Hand {
+            cards: deal.cards.clone(),
+            bid: deal.bid,
+            hand_type: to_hand_type(&deal.cards),
+            rank: CARD_RANK,
         })
         .collect();
 
     hands.sort_by(|a, b| b.cmp(a));
 
-    let mut rank = 1;
     let mut result = 0;
-    for hand in hands.iter() {
+    for (hand, rank) in hands.iter().zip(1..) {
         let value = hand.bid * rank;
         result += value;
-        rank += 1;
     }
 
     result
 }
 
-fn part2(input: &str) -> u64 {
-    let mut hands: Vec<_> = input
-        .lines()
-        .map(|line| {
-            let values: Vec<_> = line.split(" ").collect();
-            assert_eq!(values.len(), 2);
-
-            Hand {
-                cards: values[0].to_string(),
-                bid: values[1].parse::<u64>().unwrap(),
-                hand_type: to_hand_type_with_joker(values[0]),
-                rank: CARD_RANK_J,
-            }
+fn part2(deals: &[Deal]) -> u64 {
+    let mut hands: Vec<_> = deals
+        .iter()
+        .map(|deal| Hand {
+            cards: deal.cards.clone(),
+            bid: deal.bid,
+            hand_type: to_hand_type_with_joker(&deal.cards),
+            rank: CARD_RANK_J,
         })
         .collect();
 
     hands.sort_by(|a, b| b.cmp(a));
 
-    let mut rank = 1;
     let mut result = 0;
-    for hand in hands.iter() {
+    for (hand, rank) in hands.iter().zip(1..) {
         result += hand.bid * rank;
-        rank += 1;
     }
 
     result
@@ -252,12 +267,6 @@ fn part2(input: &str) -> u64 {
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = "32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483";
-
     #[test]
     fn test_find_hand_type() {
         assert_eq!(to_hand_type("AAAAA"), HandType::FiveKind);
@@ -300,31 +309,28 @@ QQQJA 483";
     }
 
     #[test]
-    fn test_part1_with_test_input() {
-        let result = part1(TEST_INPUT);
-
-        assert_eq!(result, 6440);
+    fn test_parse_deal_reports_bad_cards() {
+        let e = Span::new("32T3K 765\nT55X5 684")
+            .lines_with(parse_deal)
+            .unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+
+        let e = Span::new("32T3 765").lines_with(parse_deal).unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
     }
 
     #[test]
     fn test_part1() {
-        let input = crate::read_from_file("inputs/day07.txt");
-        let result = part1(&input);
+        let input = crate::read_input(7).unwrap();
+        let result = part1(&Span::new(&input).lines_with(parse_deal).unwrap());
 
         assert_eq!(result, 241344943);
     }
 
-    #[test]
-    fn test_part2_with_test_input() {
-        let result = part2(TEST_INPUT);
-
-        assert_eq!(result, 5905);
-    }
-
     #[test]
     fn test_part2() {
-        let input = crate::read_from_file("inputs/day07.txt");
-        let result = part2(&input);
+        let input = crate::read_input(7).unwrap();
+        let result = part2(&Span::new(&input).lines_with(parse_deal).unwrap());
 
         assert_eq!(result, 243101568);
     }
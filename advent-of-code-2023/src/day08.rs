@@ -1,32 +1,48 @@
+use aoc_core::math;
+use aoc_core::{Answer, ParseError, Solution, Span};
 use std::collections::HashMap;
 
-pub fn solution(input: String) {
-    let (instruction, maps) = parse_input(&input);
+pub struct Day08;
 
-    let result = part1(&instruction, &maps);
-    println!("part1: {:?}", result);
+impl Solution for Day08 {
+    type Parsed = (String, HashMap<String, Map>);
 
-    let result = part2(&instruction, &maps);
-    println!("part2: {:?}", result);
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_input(input)?)
+    }
+    fn part1((instruction, maps): &Self::Parsed) -> Answer {
+        Answer::or_error(part1(instruction, maps), "there is no way from AAA to ZZZ")
+    }
+    fn part2((instruction, maps): &Self::Parsed) -> Answer {
+        Answer::or_error(part2(instruction, maps), "a ghost never reaches a Z node")
+    }
 }
 
-fn part1(instruction: &String, maps: &HashMap<String, Map>) -> u64 {
-    let mut current = maps.get("AAA").unwrap();
-    let mut count = 0;
-
-    while instruction.chars().all(|c| {
-        count += 1;
+fn part1(instruction: &str, maps: &HashMap<String, Map>) -> Option<u64> {
+    steps_until(instruction, maps, "AAA", |node| node == "ZZZ")
+}
 
+/// How many steps it takes from `start` to a node that is `done`, or `None`
+/// if there is no `start` or the instructions never lead to such a node.
+fn steps_until(
+    instruction: &str,
+    maps: &HashMap<String, Map>,
+    start: &str,
+    done: impl Fn(&str) -> bool,
+) -> Option<u64> {
+    let mut current = maps.get(start)?;
+    // by then some node has been reached at the same point of the
+    // instructions twice, and it is going round in circles
+    let limit = maps.len() * instruction.len();
+
+    for (count, c) in (1..).zip(instruction.chars().cycle().take(limit)) {
         let next = current.next(c);
-        if next == "ZZZ" {
-            false
-        } else {
-            current = maps.get(next).unwrap();
-            true
+        if done(next) {
+            return Some(count);
         }
-    }) {}
-
-    return count;
+        current = &maps[next];
+    }
+    None
 }
 
 #[derive(Debug)]
@@ -36,18 +52,14 @@ struct Path {
 }
 impl Path {
     pub fn update(&mut self, node: String) {
-        if node.ends_with('Z') {
-            self.arrived = true;
-        } else {
-            self.arrived = false;
-        }
+        self.arrived = node.ends_with('Z');
         self.current_node = node;
     }
 }
 
 // brute-force way : timeout
 #[allow(dead_code)]
-fn part2_timeout(instruction: &String, maps: &HashMap<String, Map>) -> u64 {
+fn part2_timeout(instruction: &str, maps: &HashMap<String, Map>) -> u64 {
     let mut paths = maps
         .iter()
         .filter(|(name, _)| name.ends_with('A'))
@@ -70,50 +82,31 @@ fn part2_timeout(instruction: &String, maps: &HashMap<String, Map>) -> u64 {
             node.update(next.destination.to_string());
         });
 
-        if paths.iter().filter(|path| path.arrived == false).count() == 0 {
+        if paths.iter().filter(|path| !path.arrived).count() == 0 {
             found = true;
         }
 
         !found
     }) {}
 
-    return count;
+    count
 }
 
-fn part2(instruction: &String, maps: &HashMap<String, Map>) -> u64 {
-    let mut paths = maps
-        .iter()
-        .filter(|(name, _)| name.ends_with('A'))
-        .map(|(name, _)| Path {
-            current_node: name.to_string(),
-            arrived: false,
-        })
-        .collect::<Vec<_>>();
-
-    let path_results = paths
-        .iter_mut()
-        .map(|node| {
-            let mut count = 0;
-
-            while instruction.chars().all(|c| {
-                count += 1;
-
-                let current = maps.get(&*node.current_node).unwrap();
-                let next = maps.get(current.next(c)).unwrap();
-                node.update(next.destination.to_string());
-
-                !node.arrived
-            }) {}
-
-            count
-        })
-        .collect::<Vec<_>>();
+fn part2(instruction: &str, maps: &HashMap<String, Map>) -> Option<u64> {
+    let path_results = maps
+        .keys()
+        .filter(|name| name.ends_with('A'))
+        .map(|name| steps_until(instruction, maps, name, |node| node.ends_with('Z')))
+        .collect::<Option<Vec<_>>>()?;
+    if path_results.is_empty() {
+        return None;
+    }
 
-    path_results.iter().cloned().fold(1, num::integer::lcm)
+    Some(math::lcm_all(&path_results))
 }
 
 #[derive(PartialEq, Debug)]
-struct Map {
+pub struct Map {
     destination: String,
     left: String,
     right: String,
@@ -130,74 +123,58 @@ impl Map {
     }
 }
 
-fn parse_input(input: &str) -> (String, HashMap<String, Map>) {
-    let instruction = input.lines().collect::<Vec<_>>()[0].to_string();
-
-    let maps = input
-        .lines()
-        .map(|line| {
-            let split = line.split("=").collect::<Vec<_>>();
-            if split.len() == 2 {
-                let destination = split[0].trim_matches(' ');
-
-                let map = split[1].split(',').collect::<Vec<_>>();
-                assert_eq!(map.len(), 2);
-
-                let left = map[0].trim_matches(' ').trim_matches('(');
-                let right = map[1].trim_matches(')').trim_matches(' ');
+/// The `L`/`R` instructions, a blank line, then one `AAA = (BBB, CCC)` line
+/// per node. Every node a line points to has to have a line of its own.
+fn parse_input(input: &str) -> Result<(String, HashMap<String, Map>), ParseError> {
+    let (instruction, nodes) = Span::new(input).split_once("\n\n")?;
+    let text = instruction.as_str();
+    if text.is_empty() {
+        return Err(instruction.error("`L` and `R` instructions"));
+    }
+    if let Some(i) = text.find(|c| c != 'L' && c != 'R') {
+        return Err(ParseError::at(input, &text[i..i + 1], "`L` or `R`"));
+    }
 
-                Some((
-                    destination.to_string(),
-                    Map {
-                        destination: destination.to_string(),
-                        left: left.to_string(),
-                        right: right.to_string(),
-                    },
-                ))
-            } else {
-                None
+    let nodes = nodes.lines_with(|line| {
+        let (destination, targets) = line.split_once("=")?;
+        let (targets, rest) = targets.strip_prefix("(")?.split_once(")")?;
+        if !rest.is_empty() {
+            return Err(rest.error("the end of the line"));
+        }
+        let (left, right) = targets.split_once(",")?;
+        Ok((destination, left, right))
+    })?;
+
+    let mut maps = HashMap::new();
+    for &(destination, left, right) in &nodes {
+        let map = Map {
+            destination: destination.as_str().to_string(),
+            left: left.as_str().to_string(),
+            right: right.as_str().to_string(),
+        };
+        if maps.insert(map.destination.clone(), map).is_some() {
+            return Err(destination.error("a node without a line yet"));
+        }
+    }
+    for (_, left, right) in nodes {
+        for target in [left, right] {
+            if !maps.contains_key(target.as_str()) {
+                return Err(target.error("a node with a line of its own"));
             }
-        })
-        .filter_map(|map| map.ok_or(()).ok())
-        .collect();
+        }
+    }
 
-    (instruction, maps)
+    Ok((text.to_string(), maps))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = "RL
-
-AAA = (BBB, CCC)
-BBB = (DDD, EEE)
-CCC = (ZZZ, GGG)
-DDD = (DDD, DDD)
-EEE = (EEE, EEE)
-GGG = (GGG, GGG)
-ZZZ = (ZZZ, ZZZ)";
-
-    const TEST_INPUT2: &str = "LLR
-
-AAA = (BBB, BBB)
-BBB = (AAA, ZZZ)
-ZZZ = (ZZZ, ZZZ)";
-
-    const TEST_INPUT_PART2: &str = "LR
-
-11A = (11B, XXX)
-11B = (XXX, 11Z)
-11Z = (11B, XXX)
-22A = (22B, XXX)
-22B = (22C, 22C)
-22C = (22Z, 22Z)
-22Z = (22B, 22B)
-XXX = (XXX, XXX)";
-
     #[test]
     fn test_parsing_input() {
-        let (instruction, maps) = parse_input(TEST_INPUT2);
+        let input = crate::read_example(8, "2").unwrap();
+        let (instruction, maps) = parse_input(&input).unwrap();
 
         assert_eq!(instruction, "LLR".to_string());
 
@@ -232,36 +209,36 @@ XXX = (XXX, XXX)";
     }
 
     #[test]
-    fn test_part1_with_test_input() {
-        let (instruction, maps) = parse_input(TEST_INPUT);
-        let result = part1(&instruction, &maps);
-        assert_eq!(result, 2);
-
-        let (instruction, maps) = parse_input(TEST_INPUT2);
+    fn test_part1() {
+        let input = crate::read_input(8).unwrap();
+        let (instruction, maps) = parse_input(&input).unwrap();
         let result = part1(&instruction, &maps);
-        assert_eq!(result, 6);
+        assert_eq!(result, Some(15989));
     }
 
     #[test]
-    fn test_part1() {
-        let input = crate::read_from_file("inputs/day08.txt");
-        let (instruction, maps) = parse_input(&input);
-        let result = part1(&instruction, &maps);
-        assert_eq!(result, 15989);
+    fn test_part2() {
+        let input = crate::read_input(8).unwrap();
+        let (instruction, maps) = parse_input(&input).unwrap();
+        let result = part2(&instruction, &maps);
+        assert_eq!(result, Some(13830919117339));
     }
 
     #[test]
-    fn test_part2_with_test_input() {
-        let (instruction, maps) = parse_input(TEST_INPUT_PART2);
-        let result = part2(&instruction, &maps);
-        assert_eq!(result, 6);
+    fn test_parse_error() {
+        let e = parse_input("LLX\n\nAAA = (AAA, AAA)").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 3));
+        let e = parse_input("LR\n\nAAA = (BBB, ZZZ)\nBBB = (AAA AAA)").unwrap_err();
+        assert_eq!((e.line, e.column), (4, 8));
+        let e = parse_input("LR\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, AAA)").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 13));
     }
 
     #[test]
-    fn test_part2() {
-        let input = crate::read_from_file("inputs/day08.txt");
-        let (instruction, maps) = parse_input(&input);
-        let result = part2(&instruction, &maps);
-        assert_eq!(result, 13830919117339);
+    fn test_no_way_out() {
+        let (instruction, maps) =
+            parse_input("LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert_eq!(part1(&instruction, &maps), None);
+        assert_eq!(part2(&instruction, &maps), None);
     }
 }
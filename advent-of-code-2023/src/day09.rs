@@ -1,24 +1,35 @@
-pub fn solution(input: String) {
-    let result = part1(&input);
-    println!("result = {}", result);
+use aoc_core::{Answer, ParseError, Solution, Span};
 
-    let result = part2(&input);
-    println!("result = {}", result);
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Parsed = Vec<Vec<i64>>;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parses_input(input)?)
+    }
+    fn part1(histories: &Self::Parsed) -> Answer {
+        part1(histories).into()
+    }
+    fn part2(histories: &Self::Parsed) -> Answer {
+        part2(histories).into()
+    }
 }
-fn parses_input(input: &str) -> Vec<Vec<i64>> {
-    input
-        .lines()
-        .map(|line| {
-            line.split(' ')
-                .map(|number| number.parse::<i64>().unwrap())
-                .collect::<Vec<_>>()
-        })
-        .collect()
+fn parses_input(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+    Span::new(input).lines_with(|line| {
+        let history: Vec<i64> = line.list(" ")?;
+        if history.is_empty() {
+            return Err(line.error("a history of values"));
+        }
+        Ok(history)
+    })
 }
 
-fn get_diff_vectors(numbers: &Vec<i64>) -> Vec<Vec<i64>> {
+/// The history and its differences, down to a row of zeros. A history too
+/// short to get there ends with an empty row, which counts as zeros.
+fn get_diff_vectors(numbers: &[i64]) -> Vec<Vec<i64>> {
     let mut diffs = Vec::new();
-    diffs.push(numbers.clone());
+    diffs.push(numbers.to_owned());
 
     while !diffs.last().unwrap().iter().all(|&val| val == 0) {
         let iter = diffs.last().unwrap().iter().as_slice().windows(2);
@@ -29,41 +40,37 @@ fn get_diff_vectors(numbers: &Vec<i64>) -> Vec<Vec<i64>> {
     diffs
 }
 
-fn predict_prev(numbers: &Vec<i64>) -> i64 {
+fn predict_prev(numbers: &[i64]) -> i64 {
     let diffs = get_diff_vectors(numbers);
 
     diffs
         .into_iter()
         .rev()
-        .fold(0, |acc, val| val.first().unwrap() - acc)
+        .fold(0, |acc, val| val.first().unwrap_or(&0) - acc)
 }
 
-fn predict_next(numbers: &Vec<i64>) -> i64 {
+fn predict_next(numbers: &[i64]) -> i64 {
     let diffs = get_diff_vectors(numbers);
 
     diffs
         .into_iter()
         .rev()
-        .fold(0, |acc, val| acc + val.last().unwrap())
+        .fold(0, |acc, val| acc + val.last().unwrap_or(&0))
 }
 
-fn part1(input: &str) -> i64 {
-    let numbers = parses_input(&input);
-
-    numbers
+fn part1(histories: &[Vec<i64>]) -> i64 {
+    histories
         .iter()
-        .map(|numbers| predict_next(numbers))
+        .map(|n| predict_next(n))
         .collect::<Vec<_>>()
         .iter()
         .sum()
 }
 
-fn part2(input: &str) -> i64 {
-    let numbers = parses_input(&input);
-
-    numbers
+fn part2(histories: &[Vec<i64>]) -> i64 {
+    histories
         .iter()
-        .map(|numbers| predict_prev(numbers))
+        .map(|n| predict_prev(n))
         .collect::<Vec<_>>()
         .iter()
         .sum()
@@ -73,15 +80,11 @@ fn part2(input: &str) -> i64 {
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = "0 3 6 9 12 15
-1 3 6 10 15 21
-10 13 16 21 30 45";
-
     const TEST_INPUT_LONG: &str = "6 10 30 80 170 314 561 1060 2170 4641 9947 20986 43640 90222 186816 388245 807350 1670120 3415956 6874320 13566998";
 
     #[test]
     fn test_parse_input() {
-        let actual = parses_input(TEST_INPUT);
+        let actual = parses_input(&crate::read_example(9, "1").unwrap()).unwrap();
 
         let expected: Vec<Vec<i64>> = vec![
             vec![0, 3, 6, 9, 12, 15],
@@ -117,37 +120,39 @@ mod tests {
     }
 
     #[test]
-    fn test_part1_with_test_input() {
-        let result = part1(TEST_INPUT);
+    fn test_parse_input_reports_bad_value() {
+        let e = parses_input("0 3 6\n1 x 6").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
 
-        assert_eq!(result, 114);
+        let e = parses_input("0 3 6\n\n1 3 6").unwrap_err();
+        assert_eq!(e.line, 2);
     }
 
     #[test]
-    fn test_part1() {
-        let input = crate::read_from_file("inputs/day09.txt");
-        let result = part1(&input);
-
-        assert_eq!(result, 2043183816);
+    fn test_predict_short_history() {
+        assert_eq!(predict_next(&[1, 2, 4]), 7);
+        assert_eq!(predict_prev(&[1, 2, 4]), 1);
+        assert_eq!(predict_next(&[5]), 5);
     }
 
     #[test]
-    fn test_part2_with_test_input() {
-        let result = part2(TEST_INPUT);
+    fn test_part1() {
+        let input = crate::read_input(9).unwrap();
+        let result = part1(&parses_input(&input).unwrap());
 
-        assert_eq!(result, 2);
+        assert_eq!(result, 2043183816);
     }
 
     #[test]
     fn test_with_target_input() {
-        let result = part1(TEST_INPUT_LONG);
+        let result = part1(&parses_input(TEST_INPUT_LONG).unwrap());
         println!("{}", result);
     }
 
     #[test]
     fn test_part2() {
-        let input = crate::read_from_file("inputs/day09.txt");
-        let result = part2(&input);
+        let input = crate::read_input(9).unwrap();
+        let result = part2(&parses_input(&input).unwrap());
 
         assert_eq!(result, 1118);
     }
@@ -1,287 +1,237 @@
+use aoc_core::{Answer, Direction, Grid, ParseError, Point, Solution};
 use std::fmt::{Debug, Formatter};
 
-pub fn solution(input: String) {
-    let result = part1(&input);
-    println!("part1: {}", result);
+pub struct Day10;
 
-    //let result = part2(&input);
-    //println!("part2: {}", result);
-}
+impl Solution for Day10 {
+    type Parsed = Map;
 
-#[derive(Clone)]
-enum Direction {
-    North,
-    South,
-    West,
-    East,
-}
-#[derive(Clone, PartialEq)]
-struct Location(i64, i64);
-impl Location {
-    fn next(&self, dir: Direction) -> Location {
-        match dir {
-            Direction::North => Location(self.0 - 1, self.1),
-            Direction::South => Location(self.0 + 1, self.1),
-            Direction::West => Location(self.0, self.1 - 1),
-            Direction::East => Location(self.0, self.1 + 1),
-        }
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_input(input)?)
     }
-}
-impl Debug for Location {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "({}, {})", self.0, self.1)
+    fn part1(map: &Self::Parsed) -> Answer {
+        part1(map).into()
+    }
+    fn part2(map: &Self::Parsed) -> Answer {
+        part2(map).into()
     }
 }
 
 struct Node {
-    is_start: bool,
-    loc: Location,
-    next: Vec<Location>,
+    loc: Point,
+    next: Vec<Point>,
 }
 impl Node {
-    fn is_connected_to(&self, loc: &Location) -> bool {
+    fn is_connected_to(&self, loc: &Point) -> bool {
         self.next.contains(loc)
     }
-    fn is_left_top_corner(&self) -> bool {
-        self.next.contains(&self.loc.next(Direction::East))
-            && self.next.contains(&self.loc.next(Direction::South))
-    }
 }
 impl Debug for Node {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self.loc)?;
-        if self.next.len() != 0 {
+        write!(f, "{}", self.loc)?;
+        if !self.next.is_empty() {
             write!(f, "->")?;
         }
-        if let Some(next) = self.next.get(0) {
-            write!(f, " {:?}", next)?;
+        if let Some(next) = self.next.first() {
+            write!(f, " {}", next)?;
         }
         if let Some(next) = self.next.get(1) {
-            write!(f, " {:?}", next)?;
+            write!(f, " {}", next)?;
         }
         write!(f, "")
     }
 }
-struct Map {
+#[derive(Debug)]
+pub struct Map {
     rows: i64,
     cols: i64,
-    start: Location,
+    start: Point,
     nodes: Vec<Vec<Node>>,
+    /// The tiles of the loop in the order they are walked, starting at `S`.
+    main_loop: Vec<Point>,
 }
 impl Map {
-    fn next_to_start(&self) -> Vec<Location> {
-        let start_loc = &self.nodes[self.start.0 as usize][self.start.1 as usize].loc;
-        let directions = vec![
-            Direction::West,
-            Direction::East,
-            Direction::North,
-            Direction::South,
+    fn node(&self, loc: &Point) -> Option<&Node> {
+        if self.is_valid(loc) {
+            Some(&self.nodes[loc.row as usize][loc.col as usize])
+        } else {
+            None
+        }
+    }
+
+    fn next_to_start(&self) -> Vec<Point> {
+        let start_loc = &self.start;
+        let directions = [
+            Direction::Left,
+            Direction::Right,
+            Direction::Up,
+            Direction::Down,
         ];
         directions
             .iter()
-            .filter(|&dir| {
-                let loc = start_loc.next(dir.clone());
-                if self.is_valid(&loc) {
-                    let next_node = &self.nodes[loc.0 as usize][loc.1 as usize];
-                    next_node.is_connected_to(start_loc)
-                } else {
-                    false
-                }
+            .map(|dir| start_loc.step(*dir))
+            .filter(|loc| {
+                self.node(loc)
+                    .is_some_and(|next_node| next_node.is_connected_to(start_loc))
             })
-            .map(|dir| start_loc.next(dir.clone()))
             .collect::<Vec<_>>()
     }
 
-    fn replace_start(&mut self) {
-        self.nodes[self.start.0 as usize][self.start.1 as usize].next = self.next_to_start();
+    /// Follows the pipes from `S` until they lead back to it, or returns the
+    /// tile where the loop breaks off.
+    fn walk_loop(&self) -> Result<Vec<Point>, Point> {
+        let mut tiles = vec![self.start];
+        let mut prev = self.start;
+        let mut current = self.nodes[self.start.row as usize][self.start.col as usize].next[0];
+        while current != self.start {
+            tiles.push(current);
+            let node = self.node(&current).ok_or(prev)?;
+            if !node.is_connected_to(&prev) {
+                return Err(current);
+            }
+            let next = *node
+                .next
+                .iter()
+                .find(|&&next| next != prev)
+                .ok_or(current)?;
+            (prev, current) = (current, next);
+        }
+        Ok(tiles)
     }
 
-    fn is_valid(&self, loc: &Location) -> bool {
-        loc.0 >= 0 && loc.0 < self.rows && loc.1 >= 0 && loc.1 < self.cols
+    fn is_valid(&self, loc: &Point) -> bool {
+        loc.row >= 0 && loc.row < self.rows && loc.col >= 0 && loc.col < self.cols
     }
 }
 
-fn parse_input(input: &str) -> Map {
-    let nodes: Vec<Vec<_>> = input
-        .lines()
+/// The character of `input` at `loc`, for error reports.
+fn tile_text(input: &str, loc: Point) -> &str {
+    let line = input.lines().nth(loc.row as usize).unwrap_or("");
+    line.get(loc.col as usize..loc.col as usize + 1)
+        .unwrap_or("")
+}
+
+/// The pipe map, with the start tile connected to the two pipes that lead
+/// into it and the loop through them traced out.
+fn parse_input(input: &str) -> Result<Map, ParseError> {
+    let tiles = Grid::parse(input, |c| "|-LJ7F.S".contains(c).then_some(c))?;
+
+    let nodes: Vec<Vec<_>> = tiles
+        .rows()
         .enumerate()
-        .map(move |(row_idx, line)| {
-            line.chars()
+        .map(|(row_idx, line)| {
+            line.iter()
                 .enumerate()
-                .map(move |(col_idx, c)| {
-                    let current = Location(row_idx as i64, col_idx as i64);
-                    let next = match &c {
-                        '|' => vec![
-                            current.next(Direction::North),
-                            current.next(Direction::South),
-                        ],
-                        '-' => vec![current.next(Direction::West), current.next(Direction::East)],
-                        'L' => vec![
-                            current.next(Direction::North),
-                            current.next(Direction::East),
-                        ],
-                        'J' => vec![
-                            current.next(Direction::North),
-                            current.next(Direction::West),
-                        ],
-                        '7' => vec![
-                            current.next(Direction::West),
-                            current.next(Direction::South),
+                .map(|(col_idx, c)| {
+                    let current = Point::new(row_idx as i64, col_idx as i64);
+                    let next = match c {
+                        '|' => vec![current.step(Direction::Up), current.step(Direction::Down)],
+                        '-' => vec![
+                            current.step(Direction::Left),
+                            current.step(Direction::Right),
                         ],
+                        'L' => vec![current.step(Direction::Up), current.step(Direction::Right)],
+                        'J' => vec![current.step(Direction::Up), current.step(Direction::Left)],
+                        '7' => vec![current.step(Direction::Left), current.step(Direction::Down)],
                         'F' => vec![
-                            current.next(Direction::South),
-                            current.next(Direction::East),
+                            current.step(Direction::Down),
+                            current.step(Direction::Right),
                         ],
-                        'S' => vec![],
                         _ => vec![],
                     };
-                    let is_start = match &c {
-                        'S' => true,
-                        _ => false,
-                    };
 
-                    Node {
-                        is_start,
-                        loc: current,
-                        next,
-                    }
+                    Node { loc: current, next }
                 })
                 .collect::<Vec<_>>()
         })
         .collect::<Vec<_>>();
 
-    let start = nodes.iter().flatten().find(|node| node.is_start).unwrap();
-
-    Map {
-        rows: input.len() as i64,
-        cols: input.lines().collect::<Vec<_>>()[0].len() as i64,
-        start: start.loc.clone(),
-        nodes,
+    let mut starts = tiles.find_all(|&c| c == 'S').map(Point::from);
+    let Some(start) = starts.next() else {
+        return Err(ParseError::at(input, "", "a start tile `S`"));
+    };
+    if let Some(other) = starts.next() {
+        return Err(ParseError::at(
+            input,
+            tile_text(input, other),
+            "a single start tile",
+        ));
     }
-}
-
-fn create_steps_vector(rows: i64, cols: i64) -> Vec<Vec<i64>> {
-    let mut val = vec![Vec::new(); rows as usize];
-    val.iter_mut().for_each(|row| {
-        row.resize(cols as usize, -1);
-    });
-
-    val
-}
-
-fn update_steps(cur: Location, map: &Map, steps: &mut Vec<Vec<i64>>) {
-    let mut next = vec![cur];
-
-    while let Some(cur) = next.pop() {
-        let mut temp_next = Vec::new();
 
-        let cur_steps = steps[cur.0 as usize][cur.1 as usize];
-        let cur_next = &map.nodes[cur.0 as usize][cur.1 as usize].next;
-        cur_next.iter().for_each(|loc| {
-            if steps[loc.0 as usize][loc.1 as usize] == -1
-                || steps[loc.0 as usize][loc.1 as usize] > cur_steps + 1
-            {
-                steps[loc.0 as usize][loc.1 as usize] = cur_steps + 1;
-                temp_next.push(loc.clone());
-            }
-        });
-
-        //steps.iter().for_each(|row| println!("{:?}", row) );
-        next.append(&mut temp_next);
+    let mut map = Map {
+        rows: tiles.height() as i64,
+        cols: tiles.width() as i64,
+        start,
+        nodes,
+        main_loop: Vec::new(),
+    };
+
+    let next = map.next_to_start();
+    if next.len() != 2 {
+        return Err(ParseError::at(
+            input,
+            tile_text(input, start),
+            "a start tile joined to exactly two pipes",
+        ));
     }
-}
-
-fn part1(input: &str) -> u64 {
-    let mut map = parse_input(input);
-    map.replace_start();
-
-    let mut steps = create_steps_vector(map.rows, map.cols);
-    steps[map.start.0 as usize][map.start.1 as usize] = 0;
-
-    update_steps(map.start.clone(), &map, &mut steps);
+    map.nodes[start.row as usize][start.col as usize].next = next;
 
-    *steps.iter().flatten().max().unwrap() as u64
+    map.main_loop = map.walk_loop().map_err(|broken| {
+        ParseError::at(
+            input,
+            tile_text(input, broken),
+            "a pipe continuing the loop",
+        )
+    })?;
+    Ok(map)
 }
 
-struct Rectangle {
-    left_top: Location,
-    right_bottom: Location,
-}
-impl Rectangle {
-    fn size(&self) -> u64 {
-        ((self.right_bottom.1 - self.left_top.1) * (self.right_bottom.0 - self.left_top.0)) as u64
-    }
-}
-fn find_rectangle(start_node: &Location, map: &Map) -> Option<Rectangle> {
-    todo!()
+/// The farthest tile from the start is halfway round the loop.
+fn part1(map: &Map) -> u64 {
+    (map.main_loop.len() / 2) as u64
 }
 
-fn part2(input: &str) -> u64 {
-    let mut map = parse_input(input);
-    map.replace_start();
-
-    let left_top_corners = map
-        .nodes
-        .iter()
-        .map(|row| {
-            row.iter()
-                .filter(|node| node.is_left_top_corner())
-                .map(|node| node.loc.clone())
-                .collect::<Vec<_>>()
-        })
-        .flatten()
-        .collect::<Vec<_>>();
+fn part2(map: &Map) -> u64 {
+    let tiles = &map.main_loop;
 
-    let rectangles = left_top_corners
+    // Shoelace formula: twice the area of the polygon through the centres of
+    // the loop tiles.
+    let twice_area = tiles
         .iter()
-        .map(|node| find_rectangle(node, &map))
-        .collect::<Vec<_>>()
-        .iter()
-        .filter(|rec| rec.is_some())
-        .map(|rec| rec.as_ref().unwrap())
-        .collect::<Vec<_>>();
+        .zip(tiles.iter().cycle().skip(1))
+        .map(|(a, b)| a.row * b.col - b.row * a.col)
+        .sum::<i64>()
+        .abs();
 
-    //TODO
-    0
+    // Pick's theorem: area = inside + boundary / 2 - 1, and the loop tiles
+    // are exactly the boundary points.
+    let boundary = tiles.len() as i64;
+    ((twice_area - boundary) / 2 + 1) as u64
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const PART1_TEST_INPUT: &str = ".....
-.S-7.
-.|.|.
-.L-J.
-.....";
-
-    const PART2_TEST_INPUT: &str = "...........
-.S-------7.
-.|F-----7|.
-.||.....||.
-.||.....||.
-.|L-7.F-J|.
-.|..|.|..|.
-.L--J.L--J.
-...........";
-
-    #[test]
-    fn test_part1_with_test_input() {
-        let result = part1(PART1_TEST_INPUT);
-
-        assert_eq!(result, 4);
-    }
-
     #[test]
     fn test_part1() {
-        let input = crate::read_from_file("inputs/day10.txt");
-        let result = part1(&input);
+        let input = crate::read_input(10).unwrap();
+        let result = part1(&parse_input(&input).unwrap());
         assert_eq!(result, 6890);
     }
 
     #[test]
-    fn test_part2() {
-        let result = part2(PART2_TEST_INPUT);
-        assert_eq!(result, 4);
+    fn test_parse_input_reports_bad_tiles() {
+        let e = parse_input(".....\n.F-7.\n.|.|.\n.L-J.\n.....").unwrap_err();
+        assert_eq!((e.line, e.column), (5, 6));
+
+        let e = parse_input(".....\n.S-7.\n.|.X.\n.L-J.\n.....").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 4));
+
+        let e = parse_input(".....\n.S-7.\n.|.|.\n.L-7.\n.....").unwrap_err();
+        assert_eq!((e.line, e.column), (4, 4));
+
+        let e = parse_input(".|...\n.S-7.\n.|.|.\n.L-J.\n.....").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 2));
     }
 }
@@ -1,37 +1,35 @@
-pub fn solution(input: String) {
-    let result = part1(&input);
-    println!("part: {}", result);
+use aoc_core::{Answer, Grid, ParseError, Point, Solution};
 
-    let result = part2(&input, 1000000);
-    println!("part2: {}", result);
-}
+pub struct Day11;
 
-#[derive(Debug, PartialEq, Clone, Copy)]
-struct Location(u64, u64);
-impl Location {
-    fn distance_to(&self, loc: Location) -> u64 {
-        let row_distance = (self.0 as i64 - loc.0 as i64).abs() as u64;
-        let col_distance = (self.1 as i64 - loc.1 as i64).abs() as u64;
+impl Solution for Day11 {
+    type Parsed = Map;
 
-        row_distance + col_distance
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_input(input)?)
+    }
+    fn part1(map: &Self::Parsed) -> Answer {
+        part1(map).into()
+    }
+    fn part2(map: &Self::Parsed) -> Answer {
+        part2(map, 1000000).into()
     }
 }
 
-struct Map {
-    rows: u64,
-    cols: u64,
-    galaxies: Vec<Location>,
+#[derive(Debug, Clone)]
+pub struct Map {
+    rows: i64,
+    cols: i64,
+    galaxies: Vec<Point>,
 }
 impl Map {
     fn expand(&mut self, mul: u64) {
         let empty_rows = (0..self.rows)
-            .into_iter()
-            .filter(|num| self.has_galaxy_in_row(*num) == false)
+            .filter(|num| !self.has_galaxy_in_row(*num))
             .collect::<Vec<_>>();
 
         let empty_cols = (0..self.cols)
-            .into_iter()
-            .filter(|num| self.has_galaxy_in_col(*num) == false)
+            .filter(|num| !self.has_galaxy_in_col(*num))
             .collect::<Vec<_>>();
 
         let new_galaxies = self
@@ -39,36 +37,36 @@ impl Map {
             .iter()
             .map(|loc| {
                 let row_expand_count =
-                    Map::value_counts_before(&empty_rows, loc.0) as u64 * (mul - 1);
+                    Map::value_counts_before(&empty_rows, loc.row) as i64 * (mul as i64 - 1);
                 let col_expand_count =
-                    Map::value_counts_before(&empty_cols, loc.1) as u64 * (mul - 1);
+                    Map::value_counts_before(&empty_cols, loc.col) as i64 * (mul as i64 - 1);
 
-                Location(loc.0 + row_expand_count, loc.1 + col_expand_count)
+                *loc + Point::new(row_expand_count, col_expand_count)
             })
             .collect::<Vec<_>>();
 
-        self.rows += empty_rows.len() as u64;
-        self.cols += empty_cols.len() as u64;
+        self.rows += empty_rows.len() as i64;
+        self.cols += empty_cols.len() as i64;
         self.galaxies = new_galaxies;
     }
 
-    fn value_counts_before(list: &Vec<u64>, num: u64) -> usize {
+    fn value_counts_before(list: &[i64], num: i64) -> usize {
         list.iter().filter(|&&n| n < num).count()
     }
 
-    fn has_galaxy_in_row(&self, row: u64) -> bool {
-        self.galaxies.iter().filter(|loc| loc.0 == row).count() != 0
+    fn has_galaxy_in_row(&self, row: i64) -> bool {
+        self.galaxies.iter().filter(|loc| loc.row == row).count() != 0
     }
 
-    fn has_galaxy_in_col(&self, col: u64) -> bool {
-        self.galaxies.iter().filter(|loc| loc.1 == col).count() != 0
+    fn has_galaxy_in_col(&self, col: i64) -> bool {
+        self.galaxies.iter().filter(|loc| loc.col == col).count() != 0
     }
 
-    fn combinations(&self) -> Vec<(Location, Location)> {
+    fn combinations(&self) -> Vec<(Point, Point)> {
         let mut combinations = Vec::new();
         for i in 0..self.galaxies.len() {
             for j in (i + 1)..self.galaxies.len() {
-                combinations.push((self.galaxies[i].clone(), self.galaxies[j].clone()));
+                combinations.push((self.galaxies[i], self.galaxies[j]));
             }
         }
 
@@ -76,51 +74,41 @@ impl Map {
     }
 }
 
-fn parse_input(input: &str) -> Map {
-    let rows = input.len() as u64;
-    let cols = input.lines().collect::<Vec<_>>()[0].len() as u64;
-
-    let galaxies = input
-        .lines()
-        .enumerate()
-        .map(|(row_idx, line)| {
-            line.chars()
-                .enumerate()
-                .filter(|(_col_idx, val)| *val == '#')
-                .map(|(col_idx, _val)| Location(row_idx as u64, col_idx as u64))
-                .collect::<Vec<_>>()
-        })
-        .flatten()
-        .collect::<Vec<_>>();
-
-    Map {
-        rows,
-        cols,
-        galaxies,
-    }
+fn parse_input(input: &str) -> Result<Map, ParseError> {
+    let image = Grid::parse(input, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+
+    Ok(Map {
+        rows: image.height() as i64,
+        cols: image.width() as i64,
+        galaxies: image.find_all(|&galaxy| galaxy).map(Point::from).collect(),
+    })
 }
 
-fn part1(input: &str) -> u64 {
-    let mut map = parse_input(&input);
+fn part1(map: &Map) -> u64 {
+    let mut map = map.clone();
     map.expand(2);
 
     let all_combinations = map.combinations();
     all_combinations
         .iter()
-        .map(|(a, b)| (*a).distance_to(*b))
+        .map(|(a, b)| a.manhattan(*b) as u64)
         .collect::<Vec<_>>()
         .iter()
         .sum()
 }
 
-fn part2(input: &str, mul: u64) -> u64 {
-    let mut map = parse_input(&input);
+fn part2(map: &Map, mul: u64) -> u64 {
+    let mut map = map.clone();
     map.expand(mul);
 
     let all_combinations = map.combinations();
     all_combinations
         .iter()
-        .map(|(a, b)| (*a).distance_to(*b))
+        .map(|(a, b)| a.manhattan(*b) as u64)
         .collect::<Vec<_>>()
         .iter()
         .sum()
@@ -130,34 +118,23 @@ fn part2(input: &str, mul: u64) -> u64 {
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = "...#......
-.......#..
-#.........
-..........
-......#...
-.#........
-.........#
-..........
-.......#..
-#...#.....";
-
     #[test]
     fn test_expand() {
-        let mut map = parse_input(TEST_INPUT);
+        let mut map = parse_input(&crate::read_example(11, "1").unwrap()).unwrap();
         assert_eq!(map.rows, 10);
         assert_eq!(map.cols, 10);
         assert_eq!(
             map.galaxies,
             vec![
-                Location(0, 3),
-                Location(1, 7),
-                Location(2, 0),
-                Location(4, 6),
-                Location(5, 1),
-                Location(6, 9),
-                Location(8, 7),
-                Location(9, 0),
-                Location(9, 4)
+                Point::new(0, 3),
+                Point::new(1, 7),
+                Point::new(2, 0),
+                Point::new(4, 6),
+                Point::new(5, 1),
+                Point::new(6, 9),
+                Point::new(8, 7),
+                Point::new(9, 0),
+                Point::new(9, 4)
             ]
         );
 
@@ -167,45 +144,49 @@ mod tests {
         assert_eq!(
             map.galaxies,
             vec![
-                Location(0, 4),
-                Location(1, 9),
-                Location(2, 0),
-                Location(5, 8),
-                Location(6, 1),
-                Location(7, 12),
-                Location(10, 9),
-                Location(11, 0),
-                Location(11, 5)
+                Point::new(0, 4),
+                Point::new(1, 9),
+                Point::new(2, 0),
+                Point::new(5, 8),
+                Point::new(6, 1),
+                Point::new(7, 12),
+                Point::new(10, 9),
+                Point::new(11, 0),
+                Point::new(11, 5)
             ]
         );
     }
 
     #[test]
-    fn test_part1_with_test_input() {
-        let result = part1(TEST_INPUT);
-        assert_eq!(result, 374);
+    fn test_parse_input_reports_bad_cell() {
+        let e = parse_input("...#\n.#.*").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 4));
+
+        let e = parse_input("...#\n.#.").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
     }
 
     #[test]
     fn test_part1() {
-        let input = crate::read_from_file("inputs/day11.txt");
-        let result = part1(&input);
+        let input = crate::read_input(11).unwrap();
+        let result = part1(&parse_input(&input).unwrap());
         assert_eq!(result, 9684228);
     }
 
     #[test]
     fn test_part2_with_test_input() {
-        let result = part2(TEST_INPUT, 10);
+        let map = parse_input(&crate::read_example(11, "1").unwrap()).unwrap();
+        let result = part2(&map, 10);
         assert_eq!(result, 1030);
 
-        let result = part2(TEST_INPUT, 100);
+        let result = part2(&map, 100);
         assert_eq!(result, 8410);
     }
 
     #[test]
     fn test_part2() {
-        let input = crate::read_from_file("inputs/day11.txt");
-        let result = part2(&input, 1000000);
+        let input = crate::read_input(11).unwrap();
+        let result = part2(&parse_input(&input).unwrap(), 1000000);
         assert_eq!(result, 483844716556);
     }
 }
@@ -1,128 +1,212 @@
-pub fn solution(input: String) {
-    let result = part1(&input);
-    println!("part1: {}", result);
-}
-
-#[derive(PartialEq, Debug)]
-struct Value {
-    c: char,
-    num: u64,
-}
-impl Value {
-    fn new(c: char, num: u64) -> Self {
-        Self { c, num }
-    }
-}
+use aoc_core::{Answer, Memo, ParseError, Solution};
 
-fn brute_force(records: &str, info: &Vec<u64>) -> u64 {
-    let mut combinations = vec![records.to_string()];
-    let mut final_combinations = Vec::new();
+pub struct Day12;
 
-    while !combinations.is_empty() {
-        let record = combinations.pop().unwrap();
-        if record.contains('?') {
-            let comb1 = record.clone().replacen('?', "#", 1);
-            let comb2 = record.clone().replacen('?', ".", 1);
+impl Solution for Day12 {
+    type Parsed = Vec<Record>;
 
-            combinations.push(comb1);
-            combinations.push(comb2);
-        } else {
-            final_combinations.push(record);
-        }
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_records(input)?)
+    }
+    fn part1(records: &Self::Parsed) -> Answer {
+        part1(records).into()
+    }
+    fn part2(records: &Self::Parsed) -> Answer {
+        part2(records).into()
     }
-
-    final_combinations
-        .iter()
-        .filter(|record| matched(record, info))
-        .count() as u64
 }
 
-fn matched(record: &str, info: &Vec<u64>) -> bool {
-    let mut values: Vec<Value> = Vec::new();
-    let mut ongoing = false;
-
-    record.chars().for_each(|c| {
-        if c == '.' {
-            ongoing = false;
-        } else {
-            if ongoing {
-                assert_eq!(values.last().unwrap().c, c);
-                values.last_mut().unwrap().num += 1;
-            } else {
-                values.push(Value { c, num: 1 });
-                ongoing = true;
-            }
-        }
-    });
-
-    values.iter().map(|val| val.num).collect::<Vec<_>>() == *info
+/// One row of the condition report: the spring states and the sizes of
+/// the damaged groups.
+#[derive(Debug)]
+pub struct Record {
+    springs: String,
+    groups: Vec<u64>,
 }
 
-fn part1(input: &str) -> u64 {
-    let counts = input
+fn parse_records(input: &str) -> Result<Vec<Record>, ParseError> {
+    input
         .lines()
         .map(|line| {
-            let split = line.split(" ").collect::<Vec<_>>();
-            assert_eq!(split.len(), 2);
+            let Some((springs, groups)) = line.split_once(' ') else {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "springs and group sizes separated by a space",
+                ));
+            };
 
-            let records = split[0];
-            let info = split[1]
+            let groups = groups
                 .split(",")
-                .map(|n| n.parse::<u64>().unwrap())
-                .collect::<Vec<_>>();
+                .map(|n| {
+                    n.parse::<u64>()
+                        .map_err(|_| ParseError::at(input, n, "a group size"))
+                })
+                .collect::<Result<_, _>>()?;
 
-            brute_force(records, &info)
+            Ok(Record {
+                springs: springs.to_string(),
+                groups,
+            })
         })
-        .collect::<Vec<_>>();
-
-    counts.iter().sum()
+        .collect()
 }
 
-fn part2(input: &str) -> u64 {
-    // to slow.. try using dynamic programming
-    let counts = input
-        .lines()
-        .map(|line| {
-            let split = line.split(" ").collect::<Vec<_>>();
-            assert_eq!(split.len(), 2);
+impl Record {
+    /// The record with its springs written `factor` times, separated by
+    /// unknown springs, and its groups repeated as often.
+    fn unfold(&self, factor: usize) -> Record {
+        Record {
+            springs: vec![self.springs.as_str(); factor].join("?"),
+            groups: self.groups.repeat(factor),
+        }
+    }
 
-            let records = split[0].repeat(5);
-            let info = split[1]
-                .repeat(5)
-                .split(",")
-                .map(|n| n.parse::<u64>().unwrap())
-                .collect::<Vec<_>>();
+    /// Whether every group has been seen once the springs run out, with
+    /// `j` groups complete and `run` damaged springs of the next one seen.
+    fn is_complete(&self, j: usize, run: u64) -> bool {
+        let groups = &self.groups;
+        j == groups.len() && run == 0 || j + 1 == groups.len() && run == groups[j]
+    }
+
+    /// How many ways the unknown springs can be filled in to match the
+    /// groups.
+    fn arrangements(&self) -> u128 {
+        let springs = self.springs.as_bytes();
+        let groups = &self.groups;
+
+        // Counted from spring `i` on, with the groups before `j` complete
+        // and `run` damaged springs of group `j` already seen.
+        let mut memo = Memo::new();
+        memo.get((0, 0, 0), |rec, &(i, j, run): &(usize, usize, u64)| {
+            if i == springs.len() {
+                return self.is_complete(j, run) as u128;
+            }
+
+            let mut count = 0;
+            if springs[i] != b'#' {
+                // operational: ends the current group, if it is complete
+                if run == 0 {
+                    count += rec.call((i + 1, j, 0));
+                } else if run == groups[j] {
+                    count += rec.call((i + 1, j + 1, 0));
+                }
+            }
+            if springs[i] != b'.' && j < groups.len() && run < groups[j] {
+                // damaged: extends the current group
+                count += rec.call((i + 1, j, run + 1));
+            }
+            count
+        })
+    }
+
+    /// Every arrangement matching the record, with the unknown springs
+    /// filled in, worked out one at a time.
+    pub fn enumerate(&self) -> impl Iterator<Item = String> + '_ {
+        let springs = self.springs.as_bytes();
+        let groups = &self.groups;
+        let mut stack = vec![(String::new(), 0usize, 0u64)];
+
+        std::iter::from_fn(move || {
+            while let Some((prefix, j, run)) = stack.pop() {
+                let i = prefix.len();
+                if i == springs.len() {
+                    if self.is_complete(j, run) {
+                        return Some(prefix);
+                    }
+                    continue;
+                }
 
-            brute_force(&records, &info)
+                if springs[i] != b'.' && j < groups.len() && run < groups[j] {
+                    stack.push((prefix.clone() + "#", j, run + 1));
+                }
+                if springs[i] != b'#' {
+                    if run == 0 {
+                        stack.push((prefix + ".", j, 0));
+                    } else if run == groups[j] {
+                        stack.push((prefix + ".", j + 1, 0));
+                    }
+                }
+            }
+            None
         })
-        .collect::<Vec<_>>();
+    }
+}
+
+fn total_arrangements(records: &[Record], factor: usize) -> u128 {
+    records
+        .iter()
+        .map(|record| record.unfold(factor).arrangements())
+        .sum()
+}
+
+fn part1(records: &[Record]) -> u128 {
+    total_arrangements(records, 1)
+}
 
-    counts.iter().sum()
+fn part2(records: &[Record]) -> u128 {
+    total_arrangements(records, 5)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = "???.### 1,1,3
-.??..??...?##. 1,1,3
-?#?#?#?#?#?#?#? 1,3,1,6
-????.#...#... 4,1,1
-????.######..#####. 1,6,5
-?###???????? 3,2,1";
+    #[derive(PartialEq, Debug)]
+    struct Value {
+        c: char,
+        num: u64,
+    }
 
-    #[test]
-    fn test_part1_with_test_input() {
-        let result = part1(TEST_INPUT);
-        assert_eq!(result, 21);
+    /// Tries every way of filling in the `?`s; only fit for checking the
+    /// counter on short records.
+    fn brute_force(records: &str, info: &Vec<u64>) -> u64 {
+        let mut combinations = vec![records.to_string()];
+        let mut final_combinations = Vec::new();
+
+        while let Some(record) = combinations.pop() {
+            if record.contains('?') {
+                let comb1 = record.clone().replacen('?', "#", 1);
+                let comb2 = record.clone().replacen('?', ".", 1);
+
+                combinations.push(comb1);
+                combinations.push(comb2);
+            } else {
+                final_combinations.push(record);
+            }
+        }
+
+        final_combinations
+            .iter()
+            .filter(|record| matched(record, info))
+            .count() as u64
+    }
+
+    fn matched(record: &str, info: &Vec<u64>) -> bool {
+        let mut values: Vec<Value> = Vec::new();
+        let mut ongoing = false;
+
+        record.chars().for_each(|c| {
+            if c == '.' {
+                ongoing = false;
+            } else {
+                if ongoing {
+                    assert_eq!(values.last().unwrap().c, c);
+                    values.last_mut().unwrap().num += 1;
+                } else {
+                    values.push(Value { c, num: 1 });
+                    ongoing = true;
+                }
+            }
+        });
+
+        values.iter().map(|val| val.num).collect::<Vec<_>>() == *info
     }
 
-    // takes so long time
     #[test]
-    #[ignore]
     fn test_part1() {
-        let input = crate::read_from_file("inputs/day12.txt");
-        let result = part1(&input);
+        let input = crate::read_input(12).unwrap();
+        let result = part1(&parse_records(&input).unwrap());
         assert_eq!(result, 7361);
     }
 
@@ -144,8 +228,45 @@ mod tests {
     }
 
     #[test]
-    fn test_part2_with_test_input() {
-        let result = part2(TEST_INPUT);
-        assert_eq!(result, 525152);
+    fn test_parse_records_reports_bad_line() {
+        let e = parse_records("???.### 1,1,3\n.??..??...?##.").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+    }
+
+    #[test]
+    fn test_arrangements_match_brute_force() {
+        let input = crate::read_example(12, "1").unwrap();
+        for record in parse_records(&input).unwrap() {
+            let expected = brute_force(&record.springs, &record.groups) as u128;
+            assert_eq!(record.arrangements(), expected, "{}", record.springs);
+            assert_eq!(
+                record.enumerate().count() as u128,
+                expected,
+                "{}",
+                record.springs
+            );
+            assert!(record
+                .enumerate()
+                .all(|springs| matched(&springs, &record.groups)));
+        }
+
+        let record = &parse_records("???.### 1,1,3").unwrap()[0];
+        assert_eq!(record.enumerate().collect::<Vec<_>>(), vec!["#.#.###"]);
+    }
+
+    #[test]
+    fn test_unfold() {
+        let record = &parse_records(".# 1").unwrap()[0];
+        let unfolded = record.unfold(5);
+        assert_eq!(unfolded.springs, ".#?.#?.#?.#?.#");
+        assert_eq!(unfolded.groups, vec![1; 5]);
+
+        let input = crate::read_example(12, "1").unwrap();
+        let counts: Vec<_> = parse_records(&input)
+            .unwrap()
+            .iter()
+            .map(|record| record.unfold(5).arrangements())
+            .collect();
+        assert_eq!(counts, vec![1, 16384, 1, 16, 2500, 506250]);
     }
 }
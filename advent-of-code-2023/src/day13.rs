@@ -1,47 +1,63 @@
-pub fn solution(input: String) {
-    let result = part1(&input);
-    println!("part1: {}", result);
+use aoc_core::{Answer, ParseError, Solution, Span};
 
-    let result = part2(&input);
-    println!("part2: {}", result);
-}
+pub struct Day13;
 
-fn parse_patterns(input: &str) -> Vec<String> {
-    let mut patterns = Vec::new();
-    let mut current = String::new();
-    input.lines().for_each(|line| {
-        if !line.is_empty() {
-            current.push_str(line);
-            current.push_str("\n");
-        } else {
-            patterns.push(current.clone());
-            current = String::new();
-        }
-    });
-    if !current.is_empty() {
-        patterns.push(current.clone());
+impl Solution for Day13 {
+    type Parsed = Vec<Pattern>;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_patterns(input)?)
+    }
+    fn part1(patterns: &Self::Parsed) -> Answer {
+        Answer::or_error(part1(patterns), "a pattern has no line of reflection")
+    }
+    fn part2(patterns: &Self::Parsed) -> Answer {
+        Answer::or_error(part2(patterns), "a pattern has no smudged reflection")
     }
+}
 
-    patterns
+/// One block of ash and rocks, a line per row.
+#[derive(Debug)]
+pub struct Pattern {
+    rows: Vec<String>,
 }
-fn part1(input: &str) -> u64 {
-    let patterns = parse_patterns(input);
+
+fn parse_patterns(input: &str) -> Result<Vec<Pattern>, ParseError> {
+    Span::new(input).blocks_with(|block| {
+        let mut rows = Vec::new();
+        for row in block.lines() {
+            let text = row.as_str();
+            if let Some(first) = rows.first().map(String::len) {
+                if text.len() != first {
+                    return Err(row.error(format!("a row of {} cells", first)));
+                }
+            }
+            if let Some(i) = text.find(|c| c != '#' && c != '.') {
+                let end = i + text[i..].chars().next().map_or(0, char::len_utf8);
+                return Err(ParseError::at(input, &text[i..end], "`#` or `.`"));
+            }
+            rows.push(text.to_string());
+        }
+        Ok(Pattern { rows })
+    })
+}
+
+/// The summary of the reflections, or `None` if a pattern has none.
+fn part1(patterns: &[Pattern]) -> Option<u64> {
     patterns.iter().map(|p| value(p, 0)).sum()
 }
 
-fn part2(input: &str) -> u64 {
-    let patterns = parse_patterns(input);
+/// As [`part1`], once every pattern has its smudge cleaned.
+fn part2(patterns: &[Pattern]) -> Option<u64> {
     patterns.iter().map(|p| value(p, 1)).sum()
 }
 
-fn value(pattern: &str, target_diff: u64) -> u64 {
-    let pattern = pattern.lines().collect::<Vec<_>>();
+fn value(pattern: &Pattern, target_diff: u64) -> Option<u64> {
+    let pattern = pattern.rows.iter().map(String::as_str).collect::<Vec<_>>();
     if let Some(row) = find_horizontal_reflection(&pattern, target_diff) {
-        return 100 * row;
-    } else if let Some(col) = find_vertical_reflection(&pattern, target_diff) {
-        return col;
+        Some(100 * row)
     } else {
-        unreachable!();
+        find_vertical_reflection(&pattern, target_diff)
     }
 }
 
@@ -55,7 +71,7 @@ fn find_horizontal_reflection(pattern: &Vec<&str>, target_diff: u64) -> Option<u
     None
 }
 
-fn difference_in_horizontally_mirror(pattern: &Vec<&str>, idx: usize) -> u64 {
+fn difference_in_horizontally_mirror(pattern: &[&str], idx: usize) -> u64 {
     let length = std::cmp::min(idx + 1, pattern.len() - idx - 1);
     let mut difference_counter = 0;
     for offset in 0..length {
@@ -69,7 +85,7 @@ fn difference_in_horizontally_mirror(pattern: &Vec<&str>, idx: usize) -> u64 {
         }
     }
 
-    return difference_counter;
+    difference_counter
 }
 
 fn find_vertical_reflection(pattern: &Vec<&str>, target_diff: u64) -> Option<u64> {
@@ -100,41 +116,13 @@ fn difference_in_vertical_mirror(pattern: &Vec<&str>, idx: usize) -> u64 {
         difference_counter += differences;
     }
 
-    return difference_counter;
+    difference_counter
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = "#.##..##.
-..#.##.#.
-##......#
-##......#
-..#.##.#.
-..##..##.
-#.#.##.#.
-
-#...##..#
-#....#..#
-..##..###
-#####.##.
-#####.##.
-..##..###
-#....#..#";
-
-    #[test]
-    fn test_part1_with_test_input() {
-        let result = part1(TEST_INPUT);
-        assert_eq!(result, 405);
-    }
-
-    #[test]
-    fn test_part2_with_test_input() {
-        let result = part2(TEST_INPUT);
-        assert_eq!(result, 400);
-    }
-
     #[test]
     fn test_find_horizontal_mirror() {
         let input = "#...##..#
@@ -168,4 +156,19 @@ mod tests {
         let result = find_vertical_reflection(&input, 0);
         assert_eq!(result, Some(5));
     }
+
+    #[test]
+    fn test_parse_patterns_reports_bad_rows() {
+        let e = parse_patterns("#.#\n.#.\n\n##.\n.x#").unwrap_err();
+        assert_eq!((e.line, e.column), (5, 2));
+
+        let e = parse_patterns("#.#\n.#.\n\n##.\n.#").unwrap_err();
+        assert_eq!((e.line, e.column), (5, 1));
+    }
+
+    #[test]
+    fn test_pattern_without_reflection() {
+        let patterns = parse_patterns("#.\n..").unwrap();
+        assert_eq!(part1(&patterns), None);
+    }
 }
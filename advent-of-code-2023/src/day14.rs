@@ -1,13 +1,24 @@
+use aoc_core::cycle::{self, Cycle};
+use aoc_core::{Answer, Direction, Grid, ParseError, Solution};
 use std::fmt::{Debug, Formatter};
 
-pub fn solution(input: String) {
-    let result = part1(&input);
-    println!("part1: {}", result);
-    let result = part2(&input);
-    println!("part2: {}", result);
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed = Platform;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_platform(input)?)
+    }
+    fn part1(platform: &Self::Parsed) -> Answer {
+        part1(platform).into()
+    }
+    fn part2(platform: &Self::Parsed) -> Answer {
+        part2(platform).into()
+    }
 }
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 enum Rock {
     Rounded,
     Cube,
@@ -23,257 +34,175 @@ impl Debug for Rock {
         write!(f, "{value}")
     }
 }
-fn part1(input: &str) -> u32 {
-    let map = create_map(input);
-    let new_map = north_tilt(&map);
-    get_load(&new_map)
-}
-
-fn create_map(input: &str) -> Vec<Vec<Rock>> {
-    let num_cols = input.lines().collect::<Vec<_>>()[0].len();
-    let num_rows = input.lines().count();
 
-    let mut map = vec![Vec::new(); num_rows];
-    map.iter_mut()
-        .for_each(|vec| vec.resize(num_cols, Rock::Empty));
-
-    for (row_idx, line) in input.lines().enumerate() {
-        line.chars().enumerate().for_each(|(col_idx, value)| {
-            let rock = if value == 'O' {
-                Rock::Rounded
-            } else if value == '#' {
-                Rock::Cube
-            } else if value == '.' {
-                Rock::Empty
-            } else {
-                unreachable!()
-            };
-
-            map[row_idx][col_idx] = rock;
-        })
-    }
-
-    map
+/// The dish full of rocks, in whatever state the last tilt left it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Platform {
+    rocks: Grid<Rock>,
 }
 
-fn north_tilt(map: &Vec<Vec<Rock>>) -> Vec<Vec<Rock>> {
-    let num_rows = map.len();
-    let num_cols = map[0].len();
-
-    let mut new_map = vec![Vec::new(); num_rows];
-    new_map
-        .iter_mut()
-        .for_each(|row| row.resize(num_cols, Rock::Empty));
-
-    for col_idx in 0..num_cols {
-        let mut last_row = 0i32;
-
-        for row_idx in 0..num_rows {
-            match map[row_idx][col_idx] {
-                Rock::Rounded => {
-                    new_map[last_row as usize][col_idx] = Rock::Rounded;
-                    last_row += 1;
-                }
-                Rock::Cube => {
-                    new_map[row_idx][col_idx] = Rock::Cube;
-                    last_row = row_idx as i32 + 1;
-                }
-                Rock::Empty => {
-                    // do nothing
-                }
-            }
-        }
-    }
-
-    new_map
+/// The tilts making up one spin cycle, in order.
+const SPIN_CYCLE: [Direction; 4] = [
+    Direction::Up,
+    Direction::Left,
+    Direction::Down,
+    Direction::Right,
+];
+
+fn parse_platform(input: &str) -> Result<Platform, ParseError> {
+    let rocks = Grid::parse(input, |value| match value {
+        'O' => Some(Rock::Rounded),
+        '#' => Some(Rock::Cube),
+        '.' => Some(Rock::Empty),
+        _ => None,
+    })?;
+    Ok(Platform { rocks })
 }
 
-fn south_tilt(map: &Vec<Vec<Rock>>) -> Vec<Vec<Rock>> {
-    let num_rows = map.len();
-    let num_cols = map[0].len();
+impl Platform {
+    /// Tilts the platform towards `dir`, so every rounded rock rolls that
+    /// way until it hits the edge, a cube rock or another rounded rock.
+    pub fn tilt(&mut self, dir: Direction) {
+        let (height, width) = (self.rocks.height(), self.rocks.width());
+        let (lines, len) = if dir.is_horizontal() {
+            (height, width)
+        } else {
+            (width, height)
+        };
 
-    let mut new_map = vec![Vec::new(); num_rows];
-    new_map
-        .iter_mut()
-        .for_each(|row| row.resize(num_cols, Rock::Empty));
+        for line in 0..lines {
+            // The k-th cell of the line, counted from the edge the rocks
+            // roll towards.
+            let pos = |k: usize| match dir {
+                Direction::Up => (k, line),
+                Direction::Down => (len - 1 - k, line),
+                Direction::Left => (line, k),
+                Direction::Right => (line, len - 1 - k),
+            };
 
-    for col_idx in 0..num_cols {
-        let mut last_row = num_rows as i32 - 1;
-        for row_idx in (0..num_rows).rev() {
-            match map[row_idx][col_idx] {
-                Rock::Rounded => {
-                    new_map[last_row as usize][col_idx] = Rock::Rounded;
-                    last_row -= 1;
-                }
-                Rock::Cube => {
-                    new_map[row_idx][col_idx] = Rock::Cube;
-                    last_row = row_idx as i32 - 1;
-                }
-                Rock::Empty => {
-                    // do nothing
+            let mut free = 0;
+            for k in 0..len {
+                match self.rocks[pos(k)] {
+                    Rock::Rounded => {
+                        self.rocks[pos(k)] = Rock::Empty;
+                        self.rocks[pos(free)] = Rock::Rounded;
+                        free += 1;
+                    }
+                    Rock::Cube => free = k + 1,
+                    Rock::Empty => {}
                 }
             }
         }
     }
 
-    new_map
-}
-
-fn east_tilt(map: &Vec<Vec<Rock>>) -> Vec<Vec<Rock>> {
-    let num_rows = map.len();
-    let num_cols = map[0].len();
-
-    let mut new_map = vec![Vec::new(); num_rows];
-    new_map
-        .iter_mut()
-        .for_each(|row| row.resize(num_cols, Rock::Empty));
-
-    for row_idx in 0..num_rows {
-        let mut last_col = num_cols as i32 - 1;
-
-        for col_idx in (0..num_cols).rev() {
-            match map[row_idx][col_idx] {
-                Rock::Rounded => {
-                    new_map[row_idx][last_col as usize] = Rock::Rounded;
-                    last_col -= 1;
-                }
-                Rock::Cube => {
-                    new_map[row_idx][col_idx] = Rock::Cube;
-                    last_col = col_idx as i32 - 1;
-                }
-                Rock::Empty => {
-                    // do nothing
-                }
-            }
+    /// Tilts the platform north, west, south and then east.
+    pub fn spin_cycle(&mut self) {
+        for dir in SPIN_CYCLE {
+            self.tilt(dir);
         }
     }
 
-    new_map
-}
-
-fn west_tilt(map: &Vec<Vec<Rock>>) -> Vec<Vec<Rock>> {
-    let num_rows = map.len();
-    let num_cols = map[0].len();
-
-    let mut new_map = vec![Vec::new(); num_rows];
-    new_map
-        .iter_mut()
-        .for_each(|row| row.resize(num_cols, Rock::Empty));
-
-    for row_idx in 0..num_rows {
-        let mut last_col = 0;
-
-        for col_idx in 0..num_cols {
-            match map[row_idx][col_idx] {
-                Rock::Rounded => {
-                    new_map[row_idx][last_col] = Rock::Rounded;
-                    last_col += 1;
-                }
-                Rock::Cube => {
-                    new_map[row_idx][col_idx] = Rock::Cube;
-                    last_col = col_idx + 1;
-                }
-                Rock::Empty => {
-                    // do nothing
-                }
-            }
-        }
+    /// The platform after every spin cycle from now on, up to the point
+    /// where the states start repeating.
+    fn spin_cycles(&self) -> Cycle<Platform> {
+        cycle::find_cycle(self.clone(), |platform| {
+            let mut next = platform.clone();
+            next.spin_cycle();
+            next
+        })
     }
 
-    new_map
-}
-
-fn get_load(map: &Vec<Vec<Rock>>) -> u32 {
-    let num_rows = map.len();
-    let num_cols = map[0].len();
-
-    let mut total_value = 0u32;
-    for row_idx in 0..num_rows {
-        let mut count = 0;
-        for col_idx in 0..num_cols {
-            if map[row_idx][col_idx] == Rock::Rounded {
-                count += 1;
-            }
-        }
-        total_value += (num_rows as u32 - row_idx as u32) * count;
+    /// The load on the north support beams after `cycles` spin cycles.
+    pub fn load_after_cycles(&self, cycles: usize) -> u32 {
+        self.spin_cycles().state_at(cycles).north_load()
     }
 
-    total_value
-}
+    fn north_load(&self) -> u32 {
+        let num_rows = self.rocks.height();
 
-fn print_map(map: &Vec<Vec<Rock>>) {
-    for row_idx in 0..map.len() {
-        for col_idx in 0..map[row_idx].len() {
-            print!("{:?} ", map[row_idx][col_idx]);
+        let mut total_value = 0u32;
+        for (row_idx, row) in self.rocks.rows().enumerate() {
+            let count = row.iter().filter(|rock| **rock == Rock::Rounded).count() as u32;
+            total_value += (num_rows as u32 - row_idx as u32) * count;
         }
-        println!();
+
+        total_value
     }
-    println!();
 }
 
-fn part2(input: &str) -> u32 {
-    // Takes too long.. I don't think eq is working properly?
-    let mut map = create_map(input);
-    let mut last_map = map.clone();
-
-    for _count in 0..1000000000 {
-        map = north_tilt(&map);
-        map = west_tilt(&map);
-        map = south_tilt(&map);
-        map = east_tilt(&map);
-
-        if last_map.iter().flatten().eq(map.iter().flatten()) {
-            break;
-        } else {
-            last_map = map.clone();
-        }
-    }
+fn part1(platform: &Platform) -> u32 {
+    let mut platform = platform.clone();
+    platform.tilt(Direction::Up);
+    platform.north_load()
+}
 
-    print_map(&map);
-    get_load(&map)
+fn part2(platform: &Platform) -> u32 {
+    platform.load_after_cycles(1_000_000_000)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = "O....#....
-O.OO#....#
-.....##...
-OO.#O....O
-.O.....O#.
-O.#..O.#.#
-..O..#O..O
-.......O..
-#....###..
-#OO..#....";
-
     #[test]
-    fn test_part1_with_test_input() {
-        let result = part1(TEST_INPUT);
-        assert_eq!(result, 136);
+    fn test_part1() {
+        let input = crate::read_input(14).unwrap();
+        let result = part1(&parse_platform(&input).unwrap());
+        assert_eq!(result, 110128);
     }
 
     #[test]
-    fn test_part1() {
-        let input = crate::read_from_file("inputs/day14.txt");
-        let result = part1(&*input);
-        assert_eq!(result, 110128);
+    fn test_spin_cycle() {
+        let input = crate::read_example(14, "1").unwrap();
+        let mut platform = parse_platform(&input).unwrap();
+        platform.spin_cycle();
+        let expected = parse_platform(
+            ".....#....
+....#...O#
+...OO##...
+.OO#......
+.....OOO#.
+.O#...O#.#
+....O#....
+......OOOO
+#...O###..
+#..OO#....",
+        )
+        .unwrap();
+        assert_eq!(platform, expected);
+
+        let platform = parse_platform(&input).unwrap();
+        assert_eq!(platform.load_after_cycles(0), 104);
+        assert_eq!(platform.load_after_cycles(1), expected.north_load());
+        let cycles = platform.spin_cycles();
+        assert_eq!((cycles.start, cycles.length), (3, 7));
     }
 
     #[test]
-    fn test_part2_with_test_input() {
-        let result = part2(TEST_INPUT);
-        assert_eq!(result, 64);
+    fn test_load_after_cycles() {
+        let input = crate::read_example(14, "1").unwrap();
+        let platform = parse_platform(&input).unwrap();
+        assert_eq!(platform.load_after_cycles(1_000_000_000), 64);
+
+        // The cycle starts after three spins and is seven long, so twelve
+        // spins go once round it and into it again.
+        let mut spun = platform.clone();
+        for cycles in 1..=12 {
+            spun.spin_cycle();
+            assert_eq!(platform.load_after_cycles(cycles), spun.north_load());
+        }
     }
 
     #[test]
     fn test_equal() {
-        let map = create_map(TEST_INPUT);
+        let input = crate::read_example(14, "1").unwrap();
+        let map = parse_platform(&input).unwrap().rocks;
         let mut new_map = map.clone();
-        assert_eq!(map.iter().flatten().eq(new_map.iter().flatten()), true);
+        assert_eq!(map, new_map);
 
-        new_map[0].iter_mut().for_each(|col| *col = Rock::Empty);
-        assert_eq!(map.iter().flatten().eq(new_map.iter().flatten()), false);
+        for col in 0..new_map.width() {
+            new_map[(0, col)] = Rock::Empty;
+        }
+        assert_ne!(map, new_map);
     }
 }
@@ -1,19 +1,61 @@
+use aoc_core::{Answer, ParseError, Solution, Span};
 use std::fmt::{Debug, Formatter};
 
-pub fn solution(input: String) {
-    let result = part1(&input);
-    println!("part1: {result}");
-    let result = part2(&input);
-    println!("part2: {result}");
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed = Vec<Step>;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_to_steps(input)?)
+    }
+    fn part1(steps: &Self::Parsed) -> Answer {
+        part1(steps).into()
+    }
+    fn part2(steps: &Self::Parsed) -> Answer {
+        part2(steps).into()
+    }
+}
+
+fn part1(steps: &[Step]) -> i32 {
+    steps.iter().map(|step| run_steps(&step.text)).sum()
+}
+
+/// One step of the initialization sequence, as written and as an operation
+/// on the boxes.
+#[derive(Debug)]
+pub struct Step {
+    text: String,
+    op: Operation,
 }
 
-fn part1(input: &str) -> i32 {
-    let steps = parse_to_steps(input);
-    steps.iter().map(|value| run_steps(value)).sum()
+#[derive(Debug, PartialEq)]
+enum Operation {
+    /// `label=focal_length`
+    Insert(String, i32),
+    /// `label-`
+    Remove(String),
 }
 
-fn parse_to_steps(input: &str) -> Vec<String> {
-    input.split(",").map(|s| s.to_string()).collect::<Vec<_>>()
+fn parse_to_steps(input: &str) -> Result<Vec<Step>, ParseError> {
+    Span::new(input).split_with(",", parse_step)
+}
+
+fn parse_step(step: Span) -> Result<Step, ParseError> {
+    let text = step.as_str();
+    let op = if let Some(label) = text.strip_suffix('-') {
+        Operation::Remove(label.to_string())
+    } else if text.contains('=') {
+        let (label, focal_length) = step.split_once("=")?;
+        Operation::Insert(label.as_str().to_string(), focal_length.parse()?)
+    } else {
+        return Err(step.error("a step ending in `-` or `=` and a focal length"));
+    };
+
+    Ok(Step {
+        text: text.to_string(),
+        op,
+    })
 }
 
 fn run_steps(input: &str) -> i32 {
@@ -46,7 +88,7 @@ impl Box {
             .lenses
             .iter()
             .enumerate()
-            .find(|(idx, l)| l.0 == label)
+            .find(|(_idx, l)| l.0 == label)
             .unwrap()
             .0;
 
@@ -57,7 +99,7 @@ impl Box {
         self.lenses.push((label.to_string(), focal_length));
     }
     fn replace(&mut self, label: &str, focal_length: i32) {
-        self.lenses.iter_mut().enumerate().for_each(|(idx, l)| {
+        self.lenses.iter_mut().for_each(|l| {
             if l.0 == label {
                 l.1 = focal_length;
             }
@@ -65,54 +107,37 @@ impl Box {
     }
 }
 
-fn parse_equal_sign(value: &str) -> (&str, i32, usize) {
-    let label = value.split("=").nth(0).unwrap();
-    let focal_length = value.split("=").nth(1).unwrap().parse::<i32>().unwrap();
-    let box_num = run_steps(label) as usize;
-
-    (label, focal_length, box_num)
-}
-fn parse_dash_sign(value: &str) -> (&str, usize) {
-    let label = value.split("-").nth(0).unwrap();
-    let box_num = run_steps(label) as usize;
-
-    (label, box_num)
-}
-
-fn part2(input: &str) -> i32 {
+fn part2(steps: &[Step]) -> i32 {
     let mut boxes = vec![Box::new(); 256];
 
-    let steps = parse_to_steps(&input);
-    steps.iter().for_each(|value| {
-        if value.contains('=') {
-            let (label, focal_length, box_num) = parse_equal_sign(value);
+    steps.iter().for_each(|step| match &step.op {
+        Operation::Insert(label, focal_length) => {
+            let box_num = run_steps(label) as usize;
 
             if boxes[box_num].contains(label) {
-                boxes[box_num].replace(label, focal_length);
+                boxes[box_num].replace(label, *focal_length);
             } else {
-                boxes[box_num].add(label, focal_length);
+                boxes[box_num].add(label, *focal_length);
             }
-        } else if value.contains('-') {
-            let (label, box_num) = parse_dash_sign(value);
+        }
+        Operation::Remove(label) => {
+            let box_num = run_steps(label) as usize;
 
             if boxes[box_num].contains(label) {
                 boxes[box_num].remove(label);
             }
-        } else {
-            unreachable!();
         }
     });
 
     boxes
         .iter()
         .enumerate()
-        .map(|(box_num, b)| {
+        .flat_map(|(box_num, b)| {
             b.lenses
                 .iter()
                 .enumerate()
                 .map(move |(l_num, l)| (box_num as i32 + 1) * (l_num as i32 + 1) * l.1)
         })
-        .flatten()
         .sum::<i32>()
 }
 
@@ -120,8 +145,6 @@ fn part2(input: &str) -> i32 {
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
-
     #[test]
     fn test_run_steps() {
         let test_cases = vec![("HASH", 52), ("rn", 0), ("qp", 1), ("cm", 0)];
@@ -132,28 +155,29 @@ mod tests {
     }
 
     #[test]
-    fn test_part1_with_test_input() {
-        let result = part1(TEST_INPUT);
-        assert_eq!(result, 1320);
+    fn test_parse_to_steps() {
+        let steps = parse_to_steps("rn=1,cm-").unwrap();
+        assert_eq!(steps[0].op, Operation::Insert("rn".to_string(), 1));
+        assert_eq!(steps[1].op, Operation::Remove("cm".to_string()));
+
+        let e = parse_to_steps("rn=1,cm,qp=3").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 6));
+
+        let e = parse_to_steps("rn=1,cm=x").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 9));
     }
 
     #[test]
     fn test_part1() {
-        let input = crate::read_from_file("inputs/day15.txt");
-        let result = part1(&input);
+        let input = crate::read_input(15).unwrap();
+        let result = part1(&parse_to_steps(&input).unwrap());
         assert_eq!(result, 521341);
     }
 
-    #[test]
-    fn test_part2_with_test_input() {
-        let result = part2(TEST_INPUT);
-        assert_eq!(result, 145);
-    }
-
     #[test]
     fn test_part2() {
-        let input = crate::read_from_file("inputs/day15.txt");
-        let result = part2(&input);
+        let input = crate::read_input(15).unwrap();
+        let result = part2(&parse_to_steps(&input).unwrap());
         assert_eq!(result, 252782);
     }
 }
@@ -1,207 +1,166 @@
+use aoc_core::{Answer, Direction, Grid, ParseError, Point, Solution};
 use std::collections::HashMap;
 
-pub fn solution(input: String) {
-    let result = part1(&input);
-    println!("part1: {}", result);
-    let result = part2(&input);
-    println!("part2: {}", result);
-}
+pub struct Day16;
 
-const UP: u8 = 0b1000;
-const RIGHT: u8 = 0b0100;
-const DOWN: u8 = 0b0010;
-const LEFT: u8 = 0b0001;
-#[derive(Clone, Debug)]
-struct Direction(u8);
-impl Direction {
-    fn has(&self, dir: &Direction) -> bool {
-        (self.0 & dir.0) != 0
-    }
-    fn set(&mut self, dir: &Direction) {
-        self.0 |= dir.0
-    }
-}
+impl Solution for Day16 {
+    type Parsed = Map;
 
-#[derive(Clone, PartialEq, Hash, Eq, Debug)]
-struct Location(i32, i32);
-impl Location {
-    fn to_right(&self) -> Location {
-        Location(self.0, self.1 + 1)
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_map(input)?)
     }
-    fn to_left(&self) -> Location {
-        Location(self.0, self.1 - 1)
+    fn part1(map: &Self::Parsed) -> Answer {
+        part1(map).into()
     }
-    fn to_up(&self) -> Location {
-        Location(self.0 - 1, self.1)
+    fn part2(map: &Self::Parsed) -> Answer {
+        part2(map).into()
     }
-    fn to_down(&self) -> Location {
-        Location(self.0 + 1, self.1)
+}
+
+/// The beam directions that have already passed through a tile, one bit
+/// per direction.
+#[derive(Clone, Copy, Debug)]
+struct Seen(u8);
+impl Seen {
+    fn has(&self, dir: Direction) -> bool {
+        (self.0 & (1 << dir as u8)) != 0
     }
-    fn to_dir(&self, dir: &Direction) -> Location {
-        if dir.0 == RIGHT {
-            self.to_right()
-        } else if dir.0 == LEFT {
-            self.to_left()
-        } else if dir.0 == UP {
-            self.to_up()
-        } else if dir.0 == DOWN {
-            self.to_down()
-        } else {
-            unreachable!()
-        }
+    fn set(&mut self, dir: Direction) {
+        self.0 |= 1 << dir as u8
     }
 }
-struct Map {
-    value: Vec<Vec<char>>,
+
+#[derive(Debug)]
+pub struct Map {
+    value: Grid<char>,
+}
+
+fn parse_map(input: &str) -> Result<Map, ParseError> {
+    let value = Grid::parse(input, |c| ".|-/\\".contains(c).then_some(c))?;
+    if value.height() == 0 {
+        return Err(ParseError::at(input, "", "a row of tiles"));
+    }
+    Ok(Map { value })
 }
 impl Map {
-    fn is_valid(&self, loc: &Location) -> bool {
-        let size = self.size();
-        loc.0 >= 0 && loc.0 < size.0 as i32 && loc.1 >= 0 && loc.1 < size.1 as i32
+    fn is_valid(&self, loc: Point) -> bool {
+        self.value.get_point(loc).is_some()
     }
 
     fn size(&self) -> (usize, usize) {
-        (self.value.len(), self.value[0].len())
+        (self.value.height(), self.value.width())
     }
 }
 
 #[derive(Debug)]
 struct State {
-    location: Location,
+    location: Point,
     direction: Direction,
 }
-fn print(map_size: (usize, usize), check: &HashMap<(i32, i32), Direction>) {
-    for row in 0..map_size.0 {
-        for col in 0..map_size.1 {
-            if check.contains_key(&(row as i32, col as i32)) {
-                print!("#");
-            } else {
-                print!(".");
-            }
-        }
-        println!();
-    }
-}
-
-fn do_next(loc: &Location, dir: u8, map: &Map) -> Option<State> {
-    let dir = Direction { 0: dir };
-    let location = loc.to_dir(&dir);
+fn do_next(loc: Point, dir: Direction, map: &Map) -> Option<State> {
+    let location = loc.step(dir);
 
-    return if map.is_valid(&location) {
+    if map.is_valid(location) {
         Some(State {
             location,
             direction: dir,
         })
     } else {
         None
-    };
+    }
 }
 
-fn part1(input: &str) -> u64 {
-    let map = Map {
-        value: input
-            .lines()
-            .map(|line| line.chars().collect::<Vec<_>>())
-            .collect::<Vec<_>>(),
-    };
-
+fn part1(map: &Map) -> u64 {
     let start = State {
-        location: Location(0, 0),
-        direction: Direction { 0: RIGHT },
+        location: Point::new(0, 0),
+        direction: Direction::Right,
     };
-    count_energized_tiles(&map, start)
+    count_energized_tiles(map, start)
 }
 
-fn part2(input: &str) -> u64 {
-    let map = Map {
-        value: input
-            .lines()
-            .map(|line| line.chars().collect::<Vec<_>>())
-            .collect::<Vec<_>>(),
-    };
-
+fn part2(map: &Map) -> u64 {
     let mut starting_points = Vec::new();
     let map_size = map.size();
     for col in 0..map_size.1 {
         starting_points.push(State {
-            location: Location(0, col as i32),
-            direction: Direction(DOWN),
+            location: Point::new(0, col as i64),
+            direction: Direction::Down,
         });
         starting_points.push(State {
-            location: Location(map_size.0 as i32 - 1, col as i32),
-            direction: Direction(UP),
+            location: Point::new(map_size.0 as i64 - 1, col as i64),
+            direction: Direction::Up,
         });
     }
     for row in 0..map_size.0 {
         starting_points.push(State {
-            location: Location(row as i32, 0),
-            direction: Direction(RIGHT),
+            location: Point::new(row as i64, 0),
+            direction: Direction::Right,
         });
         starting_points.push(State {
-            location: Location(row as i32, map_size.1 as i32 - 1),
-            direction: Direction(LEFT),
+            location: Point::new(row as i64, map_size.1 as i64 - 1),
+            direction: Direction::Left,
         });
     }
 
     starting_points
         .into_iter()
-        .map(|start| count_energized_tiles(&map, start))
+        .map(|start| count_energized_tiles(map, start))
         .max()
         .unwrap()
 }
 fn count_energized_tiles(map: &Map, start: State) -> u64 {
-    let mut beam = HashMap::<Location, Direction>::new();
+    let mut beam = HashMap::<Point, Seen>::new();
     let mut next_values = vec![start];
 
     let direction_map: HashMap<_, _> = HashMap::from([
-        (('|', LEFT), vec![UP, DOWN]),
-        (('|', RIGHT), vec![UP, DOWN]),
-        (('|', UP), vec![UP]),
-        (('|', DOWN), vec![DOWN]),
-        (('-', UP), vec![LEFT, RIGHT]),
-        (('-', DOWN), vec![LEFT, RIGHT]),
-        (('-', LEFT), vec![LEFT]),
-        (('-', RIGHT), vec![RIGHT]),
-        (('/', RIGHT), vec![UP]),
-        (('/', UP), vec![RIGHT]),
-        (('/', LEFT), vec![DOWN]),
-        (('/', DOWN), vec![LEFT]),
-        (('\\', RIGHT), vec![DOWN]),
-        (('\\', DOWN), vec![RIGHT]),
-        (('\\', LEFT), vec![UP]),
-        (('\\', UP), vec![LEFT]),
-        (('.', LEFT), vec![LEFT]),
-        (('.', RIGHT), vec![RIGHT]),
-        (('.', UP), vec![UP]),
-        (('.', DOWN), vec![DOWN]),
+        (('|', Direction::Left), vec![Direction::Up, Direction::Down]),
+        (
+            ('|', Direction::Right),
+            vec![Direction::Up, Direction::Down],
+        ),
+        (('|', Direction::Up), vec![Direction::Up]),
+        (('|', Direction::Down), vec![Direction::Down]),
+        (
+            ('-', Direction::Up),
+            vec![Direction::Left, Direction::Right],
+        ),
+        (
+            ('-', Direction::Down),
+            vec![Direction::Left, Direction::Right],
+        ),
+        (('-', Direction::Left), vec![Direction::Left]),
+        (('-', Direction::Right), vec![Direction::Right]),
+        (('/', Direction::Right), vec![Direction::Up]),
+        (('/', Direction::Up), vec![Direction::Right]),
+        (('/', Direction::Left), vec![Direction::Down]),
+        (('/', Direction::Down), vec![Direction::Left]),
+        (('\\', Direction::Right), vec![Direction::Down]),
+        (('\\', Direction::Down), vec![Direction::Right]),
+        (('\\', Direction::Left), vec![Direction::Up]),
+        (('\\', Direction::Up), vec![Direction::Left]),
+        (('.', Direction::Left), vec![Direction::Left]),
+        (('.', Direction::Right), vec![Direction::Right]),
+        (('.', Direction::Up), vec![Direction::Up]),
+        (('.', Direction::Down), vec![Direction::Down]),
     ]);
 
-    while !next_values.is_empty() {
-        let current = next_values.pop().unwrap();
-
-        if let Some(val) = beam.get_mut(&current.location) {
-            if val.has(&current.direction) {
-                continue;
-            } else {
-                val.set(&current.direction);
-            }
-        } else {
-            beam.insert(current.location.clone(), current.direction.clone());
+    while let Some(current) = next_values.pop() {
+        let seen = beam.entry(current.location).or_insert(Seen(0));
+        if seen.has(current.direction) {
+            continue;
         }
+        seen.set(current.direction);
 
-        let c = map.value[current.location.0 as usize][current.location.1 as usize];
-        let directions = &direction_map[&(c, current.direction.0)];
+        let c = map.value[current.location.to_pos().unwrap()];
+        let directions = &direction_map[&(c, current.direction)];
 
         for dir in directions {
-            if let Some(next) = do_next(&current.location, *dir, &map) {
+            if let Some(next) = do_next(current.location, *dir, map) {
                 next_values.push(next);
             }
         }
     }
 
-    // let map_size = map.size();
-    //print(map_size, &beam);
-
     beam.len() as u64
 }
 
@@ -209,33 +168,19 @@ fn count_energized_tiles(map: &Map, start: State) -> u64 {
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = ".|...\\....
-|.-.\\.....
-.....|-...
-........|.
-..........
-.........\\
-..../.\\\\..
-.-.-/..|..
-.|....-|.\\
-..//.|....";
-
-    #[test]
-    fn test_part1_with_test_input() {
-        let result = part1(TEST_INPUT);
-        assert_eq!(result, 46);
-    }
-
     #[test]
     fn test_part1() {
-        let input = crate::read_from_file("inputs/day16.txt");
-        let result = part1(&input);
+        let input = crate::read_input(16).unwrap();
+        let result = part1(&parse_map(&input).unwrap());
         assert_eq!(result, 6795);
     }
 
     #[test]
-    fn test_part2_with_test_input() {
-        let result = part2(TEST_INPUT);
-        assert_eq!(result, 51);
+    fn test_parse_map_reports_bad_tile() {
+        let e = parse_map(".|..\n..#.").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
+
+        let e = parse_map("").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
     }
 }
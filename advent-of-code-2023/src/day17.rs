@@ -1,160 +1,145 @@
-use std::cmp::Ordering;
-use std::collections::BinaryHeap;
+use aoc_core::search;
+use aoc_core::{Answer, Direction, Grid, ParseError, Point, Solution};
 
-pub fn solution(input: String) {
-    let result = part1(&input);
-    println!("part1: {}", result);
-}
+pub struct Day17;
 
-fn part1(input: &str) -> u32 {
-    let map: Vec<Vec<u32>> = input
-        .lines()
-        .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
-        .collect();
-    let num_rows = map.len();
-    let num_cols = map[0].len();
-
-    let mut distances = vec![Vec::new(); num_rows];
-    distances
-        .iter_mut()
-        .for_each(|row| row.resize(num_cols, u32::MAX));
-    distances[0][0] = map[0][0];
-
-    let mut queue = BinaryHeap::new();
-    queue.push(Node {
-        position: Position(0, 0),
-        dir: Vec::new(),
-        cost: 0,
-    });
-
-    while let Some(node) = queue.pop() {
-        println!("current updating node: {:?}", node);
-        let current_pos = &node.position;
-        'dir: for dir in &[
-            Direction::Up,
-            Direction::Down,
-            Direction::Left,
-            Direction::Right,
-        ] {
-            if let Some(adj) = current_pos.adj_to(dir, num_rows, num_cols) {
-                if distances[adj.0][adj.1] < map[adj.0][adj.1] {
-                    continue 'dir;
-                }
-
-                // FIXME: when there are multiple shortest paths, keep all to find result that doesn't break the 3-streak rule
-                if let Some(recent_3) = node.dir.windows(2).last() {
-                    if recent_3.iter().all(|d| d == dir) {
-                        continue 'dir;
-                    }
-                }
-
-                let next_distance = distances[current_pos.0][current_pos.1] + map[adj.0][adj.1];
-                if distances[adj.0][adj.1] > next_distance {
-                    distances[adj.0][adj.1] = next_distance;
-
-                    let mut dir_history = node.dir.clone();
-                    dir_history.push(*dir);
-
-                    queue.push(Node {
-                        position: adj.clone(),
-                        dir: dir_history,
-                        cost: next_distance,
-                    });
-
-                    //println!("adj(to {:?}): {:?}, distance: {next_distance}", dir,  adj);
-                }
-            }
-        }
+impl Solution for Day17 {
+    type Parsed = Grid<u32>;
 
-        //println!();
-        //println!("{:?}", queue);
-        distances.iter().for_each(|row| {
-            row.iter().for_each(|n| {
-                if *n == u32::MAX {
-                    print!("INF")
-                } else {
-                    print!("{n:3} ")
-                }
-            });
-            println!();
-        });
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_map(input)?)
+    }
+    fn part1(map: &Self::Parsed) -> Answer {
+        Answer::or_error(part1(map), "the crucible cannot reach the factory")
+    }
+    fn part2(map: &Self::Parsed) -> Answer {
+        Answer::or_error(part2(map), "the ultra crucible cannot reach the factory")
     }
-
-    distances[num_rows - 1][num_cols - 1]
 }
 
-fn in_range(pos: (i32, i32), rows: usize, cols: usize) -> bool {
-    pos.0 >= 0 && pos.0 < rows as i32 && pos.1 >= 0 && pos.1 < cols as i32
+fn parse_map(input: &str) -> Result<Grid<u32>, ParseError> {
+    Grid::parse(input, |c| c.to_digit(10))
 }
-#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Clone)]
-struct Position(usize, usize);
-impl Position {
-    fn adj_to(&self, dir: &Direction, rows: usize, cols: usize) -> Option<Position> {
-        let new_pos = match dir {
-            Direction::Up => (self.0 as i32 - 1, self.1 as i32),
-            Direction::Down => (self.0 as i32 + 1, self.1 as i32),
-            Direction::Left => (self.0 as i32, self.1 as i32 - 1),
-            Direction::Right => (self.0 as i32, self.1 as i32 + 1),
-        };
 
-        if in_range(new_pos, rows, cols) {
-            Some(Position(new_pos.0 as usize, new_pos.1 as usize))
-        } else {
-            None
-        }
-    }
+/// How far a crucible has to go in a straight line before it may turn or
+/// stop, and how far it may go before it has to turn.
+#[derive(Debug, Clone, Copy)]
+pub struct Crucible {
+    min_run: usize,
+    max_run: usize,
 }
 
-#[derive(Ord, PartialOrd, Eq, PartialEq, Clone, Debug, Copy)]
-enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
+pub const CRUCIBLE: Crucible = Crucible::new(0, 3);
+
+pub const ULTRA_CRUCIBLE: Crucible = Crucible::new(4, 10);
+
+/// Where the crucible is, which way it is heading and how many blocks it has
+/// moved that way without turning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct State {
+    position: Point,
+    dir: Direction,
+    run: usize,
 }
 
-#[derive(Debug, Eq, PartialEq)]
-struct Node {
-    position: Position,
-    dir: Vec<Direction>,
-    cost: u32,
+/// The cheapest way from the top-left block to the bottom-right one.
+#[derive(Debug)]
+pub struct Route {
+    pub heat_loss: u32,
+    /// Every block on the way, both ends included.
+    pub blocks: Vec<Point>,
 }
-impl Ord for Node {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.cost.cmp(&self.cost)
+
+impl Crucible {
+    pub const fn new(min_run: usize, max_run: usize) -> Self {
+        Crucible { min_run, max_run }
     }
-}
-impl PartialOrd for Node {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+
+    /// The moves allowed from `state`: straight on while the run is not at
+    /// its maximum, or a quarter turn either way once it is long enough.
+    /// The start state has not moved yet, so it may head anywhere.
+    fn moves(&self, map: &Grid<u32>, state: &State) -> Vec<(State, u32)> {
+        let mut turns = Vec::with_capacity(3);
+        if state.run < self.max_run {
+            turns.push((state.dir, state.run + 1));
+        }
+        if state.run >= self.min_run || state.run == 0 {
+            turns.push((state.dir.turn_left(), 1));
+            turns.push((state.dir.turn_right(), 1));
+        }
+
+        turns
+            .into_iter()
+            .filter_map(|(dir, run)| {
+                let position = state.position.step(dir);
+                let heat_loss = map.get_point(position)?;
+                Some((State { position, dir, run }, *heat_loss))
+            })
+            .collect()
+    }
+
+    /// The route losing the least heat, or `None` if the crucible cannot
+    /// get to the bottom-right block at all.
+    pub fn best_route(&self, map: &Grid<u32>) -> Option<Route> {
+        let end = Point::new(map.height() as i64 - 1, map.width() as i64 - 1);
+        let start = State {
+            position: Point::ORIGIN,
+            dir: Direction::Right,
+            run: 0,
+        };
+
+        let path = search::dijkstra(
+            start,
+            |state| self.moves(map, state),
+            |state| state.position == end && state.run >= self.min_run,
+        )?;
+
+        Some(Route {
+            heat_loss: path.cost,
+            blocks: path.states.iter().map(|state| state.position).collect(),
+        })
     }
 }
 
+fn part1(map: &Grid<u32>) -> Option<u32> {
+    Some(CRUCIBLE.best_route(map)?.heat_loss)
+}
+
+fn part2(map: &Grid<u32>) -> Option<u32> {
+    Some(ULTRA_CRUCIBLE.best_route(map)?.heat_loss)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = "2413432311323
-3215453535623
-3255245654254
-3446585845452
-4546657867536
-1438598798454
-4457876987766
-3637877979653
-4654967986887
-4564679986453
-1224686865563
-2546548887735
-4322674655533";
-    const SIMPLE_TEST_INPUT: &str = "2413
-3215
-3255
-3446";
+    #[test]
+    fn test_route() {
+        let map = parse_map(&crate::read_example(17, "1").unwrap()).unwrap();
+        let route = CRUCIBLE.best_route(&map).unwrap();
+        assert_eq!(route.blocks[0], Point::ORIGIN);
+        assert_eq!(route.blocks[route.blocks.len() - 1], Point::new(12, 12));
+
+        // The heat loss is that of every block entered, and no run is longer
+        // than three blocks.
+        let entered: u32 = route.blocks[1..]
+            .iter()
+            .map(|&block| *map.get_point(block).unwrap())
+            .sum();
+        assert_eq!(entered, route.heat_loss);
+        let steps: Vec<Point> = route.blocks.windows(2).map(|w| w[1] - w[0]).collect();
+        assert!(steps.windows(4).all(|w| w.iter().any(|&step| step != w[0])));
+    }
 
     #[test]
-    fn test_part1_with_test_input() {
-        let result = part1(TEST_INPUT);
-        assert_eq!(result, 102);
+    fn test_unreachable() {
+        // The ultra crucible cannot stop on the start block, nor get four
+        // blocks away from it on a 4x4 map.
+        let map = parse_map("1").unwrap();
+        assert_eq!(part1(&map), Some(0));
+        assert_eq!(part2(&map), None);
+
+        let map = parse_map(&crate::read_example(17, "2").unwrap()).unwrap();
+        assert_eq!(part2(&map), None);
     }
 }
@@ -1,5 +1,5 @@
-use std::fs::File;
-use std::io::Read;
+pub const YEAR: u16 = 2023;
+pub const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
 
 pub mod day01;
 pub mod day02;
@@ -19,10 +19,4 @@ pub mod day15;
 pub mod day16;
 pub mod day17;
 
-pub fn read_from_file(filename: &str) -> String {
-    let mut file = File::open(filename).unwrap();
-    let mut buffer = String::new();
-    file.read_to_string(&mut buffer).unwrap();
-
-    buffer
-}
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
@@ -0,0 +1,81 @@
+use aoc_core::{Answer, ParseError, Solution, Span};
+use std::collections::HashMap;
+
+type Lists = (Vec<i32>, Vec<i32>);
+
+fn parse_input(input: &str) -> Result<Lists, ParseError> {
+    let pairs: Vec<(i32, i32)> = Span::new(input).lines_with(|line| line.pair(" "))?;
+    Ok(pairs.into_iter().unzip())
+}
+
+fn solve1((left, right): &Lists) -> i32 {
+    let (mut left, mut right) = (left.clone(), right.clone());
+    left.sort();
+    right.sort();
+
+    left.iter()
+        .enumerate()
+        .fold(0, |acc, (idx, val)| acc + (val - right[idx]).abs())
+}
+
+fn solve2((left, right): &Lists) -> i32 {
+    let mut counters = HashMap::<i32, i32>::new();
+    left.iter().for_each(|&l| {
+        let count: Vec<i32> = right
+            .iter()
+            .filter(|r| **r == l)
+            .copied()
+            .collect::<Vec<i32>>();
+        counters.insert(l, count.len() as i32);
+    });
+
+    left.iter().fold(0, |acc, x| acc + x * counters[x])
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed = Lists;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_input(input)?)
+    }
+    fn part1(lists: &Self::Parsed) -> Answer {
+        solve1(lists).into()
+    }
+    fn part2(lists: &Self::Parsed) -> Answer {
+        solve2(lists).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1() {
+        let test_input = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+        assert_eq!(solve1(&parse_input(test_input).unwrap()), 11);
+    }
+    #[test]
+    fn part2() {
+        let test_input = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+        assert_eq!(solve2(&parse_input(test_input).unwrap()), 31);
+    }
+    #[test]
+    fn parse_error() {
+        let e = parse_input("3   4\n4   x").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 5));
+    }
+}
@@ -0,0 +1,108 @@
+use aoc_core::{Answer, ParseError, Solution, Span};
+use crate::is_same_vec;
+
+fn get_reports(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    Span::new(input).lines_with(|line| line.list(" "))
+}
+
+
+fn is_in_range(val1: i32, val2: i32) -> bool {
+    (1..=3).contains(&val1.abs_diff(val2))
+}
+
+fn is_safe_part1(report: &[i32]) -> bool {
+    let mut sorted = report.to_owned();
+    sorted.sort();
+    let is_ascending = is_same_vec(&sorted, report);
+
+    sorted.sort_by(|a, b| b.cmp(a));
+    let is_descending = is_same_vec(&sorted, report);
+
+    if is_ascending ^ is_descending {
+        for i in 1..sorted.len() {
+            if !is_in_range(sorted[i], sorted[i - 1]) {
+                return false;
+            }
+        }
+        return true;
+    }
+
+    false
+}
+
+#[allow(dead_code)]
+fn is_safe_part1_v1(report: &[i32]) -> bool {
+    assert!(report.len() >= 2);
+
+    let mut prev = report[0];
+    let is_increasing = report[0] < report[1];
+
+    for &val in &report[1..] {
+        if !is_in_range(val, prev) {
+            return false;
+        }
+
+        if is_increasing && prev > val {
+            return false;
+        }
+        if !is_increasing && prev < val {
+            return false;
+        }
+
+        prev = val;
+    }
+
+    true
+}
+
+fn is_safe_part2(report: &[i32]) -> bool {
+    if is_safe_part1(report) {
+        return true;
+    }
+
+    for i in 0..report.len() {
+        let partial: Vec<i32> = report
+            .iter()
+            .enumerate()
+            .filter(|(idx, _val)| *idx != i)
+            .map(|(_idx, val)| *val)
+            .collect();
+
+        if is_safe_part1(&partial) {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn scan_report(reports: &[Vec<i32>], is_safe: fn(&[i32]) -> bool) -> usize {
+    reports
+        .iter()
+        .filter(|r| {
+            is_safe(r)
+        })
+        .count()
+}
+
+fn part1(reports: &[Vec<i32>]) -> usize {
+    scan_report(reports, is_safe_part1)
+}
+fn part2(reports: &[Vec<i32>]) -> usize {
+    scan_report(reports, is_safe_part2)
+}
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(get_reports(input)?)
+    }
+    fn part1(reports: &Self::Parsed) -> Answer {
+        part1(reports).into()
+    }
+    fn part2(reports: &Self::Parsed) -> Answer {
+        part2(reports).into()
+    }
+}
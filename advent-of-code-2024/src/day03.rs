@@ -1,21 +1,21 @@
-use advent_of_code_2024::read_from_file;
+use aoc_core::{Answer, ParseError, Solution};
 
 #[derive(Debug)]
-enum Operation {
+pub enum Operation {
     Mul(MultiplyOp),
     Do,
     Dont,
 }
 
 #[derive(Debug)]
-struct MultiplyOp {
+pub struct MultiplyOp {
     val1: i32,
     val2: i32,
 }
 
 impl MultiplyOp {
     fn execute(&self) -> i32 {
-        return self.val1 * self.val2;
+        self.val1 * self.val2
     }
 }
 
@@ -61,7 +61,7 @@ impl Scanner {
                 break;
             }
         }
-        return num;
+        num
     }
 
     fn ended(&self) -> bool {
@@ -109,10 +109,20 @@ impl Scanner {
     }
 }
 
-fn part1(input: String) -> i32 {
+/// The scanner steps through the memory a byte at a time, so it has to be
+/// plain ASCII.
+fn parse_operations(input: &str) -> Result<Vec<Operation>, ParseError> {
+    if let Some((i, c)) = input.char_indices().find(|(_, c)| !c.is_ascii()) {
+        let text = &input[i..i + c.len_utf8()];
+        return Err(ParseError::at(input, text, "an ASCII character"));
+    }
+    let mut scan = Scanner::new(input.to_string());
+    Ok(std::iter::from_fn(|| scan.find_next_op()).collect())
+}
+
+fn part1(operations: &[Operation]) -> i32 {
     let mut result = 0;
-    let mut scan = Scanner::new(input);
-    while let Some(op) = scan.find_next_op() {
+    for op in operations {
         match op {
             Operation::Mul(multiply_op) => {
                 result += multiply_op.execute();
@@ -124,11 +134,10 @@ fn part1(input: String) -> i32 {
     result
 }
 
-fn part2(input: String) -> i32 {
+fn part2(operations: &[Operation]) -> i32 {
     let mut result = 0;
     let mut can_execute = true;
-    let mut scan = Scanner::new(input);
-    while let Some(op) = scan.find_next_op() {
+    for op in operations {
         match op {
             Operation::Mul(multiply_op) => {
                 if can_execute {
@@ -147,27 +156,36 @@ fn part2(input: String) -> i32 {
     result
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let input = read_from_file("inputs/day03.txt")?;
-    println!("part1 = {}", part1(input.clone()));
-    println!("part2 = {}", part2(input.clone()));
-    Ok(())
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = Vec<Operation>;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_operations(input)?)
+    }
+    fn part1(operations: &Self::Parsed) -> Answer {
+        part1(operations).into()
+    }
+    fn part2(operations: &Self::Parsed) -> Answer {
+        part2(operations).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
-    const INPUT2: &str =
-        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
-    #[test]
-    fn test_part1() {
-        assert_eq!(part1(String::from(INPUT)), 161);
-    }
-
     #[test]
-    fn test_part2() {
-        assert_eq!(part2(String::from(INPUT2)), 48);
+    fn test_parse_operations() {
+        let operations = parse_operations(
+            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))",
+        )
+        .unwrap();
+        assert_eq!(part1(&operations), 161);
+        assert_eq!(part2(&operations), 48);
+
+        let e = parse_operations("mul(2,4)\nmul(3,4)é").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 9));
     }
 }
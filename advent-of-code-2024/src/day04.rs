@@ -0,0 +1,134 @@
+use aoc_core::{Answer, Grid, ParseError, Solution};
+
+pub struct Map {
+    grid: Grid<char>,
+}
+
+#[allow(dead_code)]
+const HORIZONTAL: i32 = 0;
+#[allow(dead_code)]
+const VERTICAL: i32 = 1;
+#[allow(dead_code)]
+const BACKWARD: i32 = 2;
+const DIAGONAL_RD: i32 = 3;
+const DIAGONAL_RU: i32 = 4;
+const DIAGONAL_LD: i32 = 5;
+const DIAGONAL_LU: i32 = 6;
+#[allow(dead_code)]
+const UPWARD: i32 = 7;
+const NUM_DIR: i32 = 8;
+
+const ROW: usize = 0;
+const COL: usize = 1;
+
+static CHARS: &[char] = &['X', 'M', 'A', 'S'];
+static DIRECTIONS: &[[&[isize]; 2]; NUM_DIR as usize] = &[
+    [&[0, 0, 0, 0], &[0, 1, 2, 3]],       // HORIZONTAL
+    [&[0, 1, 2, 3], &[0, 0, 0, 0]],       // VERTICAL
+    [&[0, 0, 0, 0], &[0, -1, -2, -3]],    // BACKWARD
+    [&[0, 1, 2, 3], &[0, 1, 2, 3]],       // DIAGONAL_RD
+    [&[0, -1, -2, -3], &[0, 1, 2, 3]],    // DIAGONAL_RU
+    [&[0, 1, 2, 3], &[0, -1, -2, -3]],    // DIAGONAL_LD
+    [&[0, -1, -2, -3], &[0, -1, -2, -3]], // DIAGONAL_LU
+    [&[0, -1, -2, -3], &[0, 0, 0, 0]],    // UPWARD
+];
+
+impl Map {
+    fn new(input: &str) -> Result<Map, ParseError> {
+        Ok(Map {
+            grid: Grid::parse(input, Some)?,
+        })
+    }
+    fn is_char(&self, pos: (usize, usize), offset: (isize, isize), ch: char) -> bool {
+        self.grid
+            .offset(pos, offset)
+            .is_some_and(|pos| self.grid[pos] == ch)
+    }
+
+    fn is_xmas(&self, pos: (usize, usize), dir: i32) -> bool {
+        let dir = &DIRECTIONS[dir as usize];
+        for i in 0..4 { // For chars in XMAS
+            if !self.is_char(
+                pos,
+                (dir[ROW][i], dir[COL][i]),
+                CHARS[i],
+            ) {
+                return false;
+            }
+        }
+        true
+    }
+    fn is_mas(&self, pos: (usize, usize), dir: i32) -> bool {
+        let dir = &DIRECTIONS[dir as usize];
+        for i in 0..3 { // For chars in  MAS
+            if !self.is_char(
+                pos,
+                (dir[ROW][i], dir[COL][i]),
+                CHARS[i + 1], // to skip 'X'
+            ) {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn find_xmas(&self) -> i32 {
+        let mut count = 0;
+
+        for pos in self.grid.find_all(|&c| c == 'X') {
+            for i in 0..NUM_DIR {
+                if self.is_xmas(pos, i) {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    fn is_x_mas_box(&self, (r, c): (usize, usize)) -> bool {
+        let mut mas_count = 0;
+
+        if self.is_mas((r, c), DIAGONAL_RD) {
+            mas_count += 1;
+        }
+        if self.grid.contains((r, c + 2)) && self.is_mas((r, c + 2), DIAGONAL_LD) {
+            mas_count += 1;
+        }
+        if self.grid.contains((r + 2, c)) && self.is_mas((r + 2, c), DIAGONAL_RU) {
+            mas_count += 1;
+        }
+        if self.grid.contains((r + 2, c + 2)) && self.is_mas((r + 2, c + 2), DIAGONAL_LU) {
+            mas_count += 1;
+        }
+        mas_count == 2
+    }
+    fn find_x_mas(&self) -> i32 {
+        self.grid
+            .positions()
+            .filter(|&pos| self.is_x_mas_box(pos))
+            .count() as i32
+    }
+}
+fn part1(map: &Map) -> i32 {
+    map.find_xmas()
+}
+
+fn part2(map: &Map) -> i32 {
+    map.find_x_mas()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed = Map;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(Map::new(input)?)
+    }
+    fn part1(map: &Self::Parsed) -> Answer {
+        part1(map).into()
+    }
+    fn part2(map: &Self::Parsed) -> Answer {
+        part2(map).into()
+    }
+}
@@ -0,0 +1,300 @@
+use aoc_core::{Answer, ParseError, Solution, Span};
+use std::collections::HashMap;
+use std::fmt;
+
+fn get_mid_value(vec: &[i32]) -> i32 {
+    vec[vec.len() / 2]
+}
+
+#[derive(Debug)]
+struct Rule {
+    before: Vec<i32>,
+    after: Vec<i32>,
+}
+
+/// Pages whose ordering rules contradict each other: each has to come
+/// before the next, and the last before the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleCycle {
+    pub pages: Vec<i32>,
+}
+
+impl fmt::Display for RuleCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "contradictory rules")?;
+        let next = self.pages.iter().cycle().skip(1);
+        for (i, (before, after)) in self.pages.iter().zip(next).enumerate() {
+            let sep = if i == 0 { " " } else { ", " };
+            write!(f, "{}{}|{}", sep, before, after)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Manual {
+    rules: HashMap<i32, Rule>,
+    pages: Vec<Vec<i32>>,
+}
+
+impl Manual {
+    /// The ordering rules, a blank line and then one update per line. An
+    /// update must list at least one page and its rules must not contradict
+    /// each other.
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let (rules, pages) = Span::new(input).split_once("\n\n")?;
+
+        let rules: Vec<(i32, i32)> = rules.lines_with(|line| line.pair("|"))?;
+
+        let mut rules_map: HashMap<i32, Rule> = HashMap::new();
+        for rule in &rules {
+            if let Some(found) = rules_map.get_mut(&rule.0) {
+                found.after.push(rule.1);
+            } else {
+                rules_map.insert(
+                    rule.0,
+                    Rule {
+                        before: Vec::new(),
+                        after: vec![rule.1],
+                    },
+                );
+            }
+
+            if let Some(found) = rules_map.get_mut(&rule.1) {
+                found.before.push(rule.0);
+            } else {
+                rules_map.insert(
+                    rule.1,
+                    Rule {
+                        before: vec![rule.0],
+                        after: Vec::new(),
+                    },
+                );
+            }
+        }
+
+        let mut manual = Manual {
+            rules: rules_map,
+            pages: Vec::new(),
+        };
+        // An update whose rules go round in a loop has no valid order, and
+        // one without pages has no middle page.
+        manual.pages = pages.lines_with(|line| {
+            let update: Vec<i32> = line.list(",")?;
+            if update.is_empty() {
+                return Err(line.error("an update with at least one page"));
+            }
+            match manual.reorder(&update) {
+                Ok(_) => Ok(update),
+                Err(cycle) => Err(line.error(format!("pages without {}", cycle))),
+            }
+        })?;
+
+        Ok(manual)
+    }
+
+    fn is_valid_pair(&self, num0: i32, num1: i32) -> bool {
+        if let Some(entry) = self.rules.get(&num0) {
+            if entry.after.contains(&num1) { return true; }
+        }
+
+        if let Some(entry) = self.rules.get(&num1) {
+            if entry.before.contains(&num0) { return true; }
+        }
+
+        false
+    }
+
+    fn valid(&self, pages: &[i32]) -> bool {
+        pages
+            .windows(2)
+            .all(|nums| self.is_valid_pair(nums[0], nums[1]))
+    }
+
+    /// The first rule `pages` breaks, as the `(before, after)` pair of pages
+    /// it has the wrong way round.
+    pub fn violated_rule(&self, pages: &[i32]) -> Option<(i32, i32)> {
+        pages.iter().enumerate().find_map(|(i, &page)| {
+            pages[..i]
+                .iter()
+                .find(|&&earlier| self.is_valid_pair(page, earlier))
+                .map(|&earlier| (page, earlier))
+        })
+    }
+
+    /// `pages` sorted so that every rule between two of them is followed,
+    /// keeping the original order wherever the rules allow it. Only the
+    /// rules between these pages count, and if those contradict each other
+    /// the pages on one such loop are returned instead.
+    pub fn reorder(&self, pages: &[i32]) -> Result<Vec<i32>, RuleCycle> {
+        // How many of the pages not yet placed have to go before each page.
+        let mut waiting: Vec<usize> = pages
+            .iter()
+            .map(|&page| {
+                pages
+                    .iter()
+                    .filter(|&&other| self.is_valid_pair(other, page))
+                    .count()
+            })
+            .collect();
+        let mut placed = vec![false; pages.len()];
+        let mut order = Vec::with_capacity(pages.len());
+
+        while order.len() < pages.len() {
+            let Some(next) = (0..pages.len()).find(|&i| !placed[i] && waiting[i] == 0) else {
+                return Err(self.rule_cycle(pages, &placed));
+            };
+            placed[next] = true;
+            order.push(pages[next]);
+            for (i, &page) in pages.iter().enumerate() {
+                if self.is_valid_pair(pages[next], page) {
+                    waiting[i] -= 1;
+                }
+            }
+        }
+
+        Ok(order)
+    }
+
+    /// A loop of rules among the pages not `placed`, every one of which is
+    /// still waiting for another of them.
+    fn rule_cycle(&self, pages: &[i32], placed: &[bool]) -> RuleCycle {
+        let left: Vec<i32> = pages
+            .iter()
+            .zip(placed)
+            .filter(|(_, &placed)| !placed)
+            .map(|(&page, _)| page)
+            .collect();
+
+        // Walking back from page to a page that has to go before it can
+        // never leave `left`, so it must come round to a page it has seen.
+        let mut path = vec![left[0]];
+        loop {
+            let page = path[path.len() - 1];
+            let before = *left
+                .iter()
+                .find(|&&other| self.is_valid_pair(other, page))
+                .expect("every page left waits for another");
+            if let Some(start) = path.iter().position(|&seen| seen == before) {
+                let mut pages = path.split_off(start);
+                pages.reverse();
+                return RuleCycle { pages };
+            }
+            path.push(before);
+        }
+    }
+
+    fn get_valid_list(&self) -> Vec<Vec<i32>> {
+        self.pages
+            .iter()
+            .filter(|list| self.valid(list)).cloned()
+            .collect()
+    }
+    fn get_invalid_list(&self) -> Vec<Vec<i32>> {
+        self.pages
+            .iter()
+            .filter(|list| !self.valid(list)).cloned()
+            .collect()
+    }
+}
+
+fn part1(manual: &Manual) -> i32 {
+    let valid = manual.get_valid_list();
+    valid.iter().map(|v| get_mid_value(v)).sum()
+}
+
+fn part2(manual: &Manual) -> i32 {
+    let invalid = manual.get_invalid_list();
+    invalid
+        .iter()
+        .map(|list| {
+            manual
+                .reorder(list)
+                .expect("parsing rejects updates with contradictory rules")
+        })
+        .map(|list| get_mid_value(&list))
+        .sum()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed = Manual;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(Manual::new(input)?)
+    }
+    fn part1(manual: &Self::Parsed) -> Answer {
+        part1(manual).into()
+    }
+    fn part2(manual: &Self::Parsed) -> Answer {
+        part2(manual).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        let manual = Manual::new(&crate::read_example(5, "1").unwrap()).unwrap();
+        assert!(manual.rules.get(&47).unwrap().after.contains(&53));
+        assert!(manual.rules.get(&53).unwrap().before.contains(&47));
+
+        assert_eq!(
+            manual.pages,
+            vec![
+                vec![75, 47, 61, 53, 29],
+                vec![97, 61, 53, 29, 13],
+                vec![75, 29, 13],
+                vec![75, 97, 47, 61, 53],
+                vec![61, 13, 29],
+                vec![97, 13, 75, 29, 47]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reorder() {
+        let manual = Manual::new(&crate::read_example(5, "1").unwrap()).unwrap();
+        assert_eq!(
+            manual.reorder(&[75, 97, 47, 61, 53]),
+            Ok(vec![97, 75, 47, 61, 53])
+        );
+        assert_eq!(manual.reorder(&[61, 13, 29]), Ok(vec![61, 29, 13]));
+        assert_eq!(
+            manual.reorder(&[97, 13, 75, 29, 47]),
+            Ok(vec![97, 75, 47, 29, 13])
+        );
+        assert_eq!(manual.reorder(&[75, 29, 13]), Ok(vec![75, 29, 13]));
+
+        assert_eq!(manual.violated_rule(&[75, 29, 13]), None);
+        assert_eq!(manual.violated_rule(&[75, 97, 47, 61, 53]), Some((97, 75)));
+        assert_eq!(manual.violated_rule(&[61, 13, 29]), Some((29, 13)));
+    }
+
+    #[test]
+    fn test_reorder_cycle() {
+        let manual = Manual::new("1|2\n2|3\n3|1\n3|4\n\n4,3,2").unwrap();
+        let cycle = manual.reorder(&[4, 3, 2, 1]).unwrap_err();
+        assert_eq!(cycle.pages, vec![1, 2, 3]);
+        assert_eq!(cycle.to_string(), "contradictory rules 1|2, 2|3, 3|1");
+
+        // The loop only matters when all of its pages are in the update.
+        assert_eq!(manual.reorder(&[4, 3, 2]), Ok(vec![2, 3, 4]));
+    }
+
+    #[test]
+    fn test_parse_cycle() {
+        let e = Manual::new("1|2\n2|3\n3|1\n\n1,2\n3,2,1").unwrap_err();
+        assert_eq!((e.line, e.column), (6, 1));
+        assert!(e.to_string().contains("contradictory rules 1|2, 2|3, 3|1"));
+    }
+
+    #[test]
+    fn test_parse_empty_update() {
+        let e = Manual::new("1|2\n\n1,2\n,\n2,1").unwrap_err();
+        assert_eq!((e.line, e.column), (4, 1));
+    }
+}
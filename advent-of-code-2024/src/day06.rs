@@ -0,0 +1,164 @@
+use aoc_core::{Answer, Direction, Grid, ParseError, Point, Solution};
+
+#[derive(Clone, Debug)]
+pub struct Map {
+    map: Grid<char>,
+    visited: Grid<bool>,
+    guard: GuardStatus,
+}
+
+#[derive(Clone, Debug)]
+struct GuardStatus {
+    loc: Point,
+    direction: Direction,
+}
+
+const OBSTACLE: char = '#';
+const GUARD: char = '^';
+
+const SAFE_LOC: char = '.';
+
+impl Map {
+    fn new(map: Grid<char>, guard_loc: (usize, usize)) -> Map {
+        let mut visited = Grid::new(map.height(), map.width(), false);
+        visited[guard_loc] = true;
+
+        Map {
+            map,
+            visited,
+            guard: GuardStatus {
+                loc: guard_loc.into(),
+                direction: Direction::Up,
+            },
+        }
+    }
+    fn blocked(&self, guard: &GuardStatus) -> bool {
+        self.map
+            .get_point(guard.loc.step(guard.direction))
+            .is_none()
+    }
+    fn get_next(&mut self, guard: GuardStatus) -> Option<GuardStatus> {
+        let next = guard.loc.step(guard.direction);
+        let pos = next.to_pos()?;
+        let cell = *self.map.get(pos)?;
+
+        if cell == SAFE_LOC || cell == GUARD {
+            self.visited[pos] = true;
+
+            Some(GuardStatus {
+                loc: next,
+                direction: guard.direction,
+            })
+        } else if cell == OBSTACLE {
+            Some(GuardStatus {
+                loc: guard.loc,
+                direction: guard.direction.turn_right(),
+            })
+        } else {
+            panic!("Should not reach here, {:?}", next)
+        }
+    }
+
+    /// Walks the guard off the map, or returns false if they end up
+    /// walking in a loop. Every step or turn is a new position and
+    /// direction until then, so there can be at most four per tile.
+    fn traverse(&mut self) -> bool {
+        let mut cur = self.guard.clone();
+        for _ in 0..4 * self.map.height() * self.map.width() {
+            if self.blocked(&cur) {
+                return true;
+            }
+            match self.get_next(cur) {
+                Some(next) => cur = next,
+                None => return true,
+            }
+        }
+        false
+    }
+
+    fn count_visited(&self) -> i32 {
+        self.visited.find_all(|&visited| visited).count() as i32
+    }
+}
+
+fn parse_input(input: &str) -> Result<Map, ParseError> {
+    let map = Grid::parse(input, |c| {
+        [SAFE_LOC, OBSTACLE, GUARD].contains(&c).then_some(c)
+    })?;
+
+    let Some(guard_loc) = map.find(|&c| c == GUARD) else {
+        return Err(ParseError::at(input, "", "a guard `^`"));
+    };
+    if let Some((_, other)) = input.match_indices(GUARD).nth(1) {
+        return Err(ParseError::at(input, other, "a single guard"));
+    }
+
+    let map = Map::new(map, guard_loc);
+    if !map.clone().traverse() {
+        let guard = input.matches(GUARD).next().unwrap_or_default();
+        return Err(ParseError::at(input, guard, "a guard who leaves the map"));
+    }
+
+    Ok(map)
+}
+
+/// How many tiles the guard visits before leaving the map, which parsing
+/// has checked they do.
+fn part1(map: &Map) -> i32 {
+    let mut map = map.clone();
+    map.traverse();
+    map.count_visited()
+}
+
+#[allow(dead_code)]
+fn part2(_map: &Map) -> i32 {
+    unimplemented!()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed = Map;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_input(input)?)
+    }
+    fn part1(map: &Self::Parsed) -> Answer {
+        part1(map).into()
+    }
+    fn part2(_map: &Self::Parsed) -> Answer {
+        Answer::Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_guard_in_loop() {
+        let e = parse_input(".#..\n...#\n#^..\n..#.").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 2));
+    }
+
+    #[test]
+    fn test_parse_input_reports_bad_tiles() {
+        let e = parse_input("..#\n.^.\n.x.").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 2));
+
+        let e = parse_input("..#\n.^.\n..^").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 3));
+
+        let e = parse_input("..#\n...").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 4));
+    }
+
+    #[test]
+    #[ignore = "part2 is not implemented yet"]
+    fn test_part2() {
+        assert_eq!(
+            part2(&parse_input(&crate::read_example(6, "1").unwrap()).unwrap()),
+            6
+        );
+    }
+}
@@ -0,0 +1,123 @@
+use aoc_core::digits;
+use aoc_core::{Answer, ParseError, Solution, Span};
+
+#[derive(Debug)]
+pub struct Equation {
+    target: u64,
+    operands: Vec<u64>,
+}
+impl Equation {
+    /// A `target: operand operand ...` line, with at least one operand.
+    pub fn new(line: Span) -> Result<Self, ParseError> {
+        let (target, operands) = line.split_once(":")?;
+        let operands: Vec<u64> = operands.list(" ")?;
+        if operands.is_empty() {
+            return Err(line.error("a target and at least one operand"));
+        }
+
+        Ok(Self {
+            target: target.parse()?,
+            operands,
+        })
+    }
+    pub fn evaluate(&self, eval_pipe: bool) -> bool {
+        let result= self.operands[0];
+        self.eval(result, 1, eval_pipe)
+    }
+
+    /// Results too big for a `u64` are dropped rather than wrapped around.
+    fn eval(&self, result: u64, index: usize, eval_pipe: bool) -> bool {
+        if index == self.operands.len() {
+            return result == self.target;
+        }
+
+        let operand = self.operands[index];
+        let piped = if eval_pipe {
+            Equation::pipe(result, operand)
+        } else {
+            None
+        };
+        [
+            result.checked_add(operand),
+            result.checked_mul(operand),
+            piped,
+        ]
+        .into_iter()
+        .flatten()
+        .any(|result| self.eval(result, index + 1, eval_pipe))
+    }
+    fn pipe(val1: u64, val2: u64) -> Option<u64> {
+        10u64
+            .checked_pow(digits::count(val2))?
+            .checked_mul(val1)?
+            .checked_add(val2)
+    }
+}
+
+fn part1(equations: &[Equation]) -> u64 {
+    equations.iter().map(|eq| {
+        if eq.evaluate(false) {
+            eq.target
+        } else {
+            0
+        }
+    }).sum()
+}
+
+fn part2(equations: &[Equation]) -> u64 {
+    equations.iter().map(|eq| {
+        if eq.evaluate(true) {
+            eq.target
+        } else {
+            0
+        }
+    }).sum()
+}
+
+fn parse_input(input: &str) -> Result<Vec<Equation>, ParseError> {
+    Span::new(input).lines_with(Equation::new)
+}
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Parsed = Vec<Equation>;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_input(input)?)
+    }
+    fn part1(equations: &Self::Parsed) -> Answer {
+        part1(equations).into()
+    }
+    fn part2(equations: &Self::Parsed) -> Answer {
+        part2(equations).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parsing() {
+        let equations = parse_input(&crate::read_example(7, "1").unwrap()).unwrap();
+        assert_eq!(equations[0].target, 190);
+        assert_eq!(equations[0].operands[0], 10);
+        assert_eq!(equations[0].operands[1], 19);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse_input("190: 10 19\n3267 81 40 27").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        let e = parse_input("190: 10 19\n3267:").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        let e = parse_input("190: 10 x9").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 9));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let equations = parse_input("5: 18446744073709551615 2 5").unwrap();
+        assert!(!equations[0].evaluate(true));
+    }
+}
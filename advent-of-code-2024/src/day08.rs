@@ -0,0 +1,137 @@
+use aoc_core::math;
+use aoc_core::{Answer, Grid, ParseError, Point, Solution};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Formatter;
+
+#[derive(Clone)]
+pub struct Map {
+    nodes: Grid<char>,
+}
+
+impl std::fmt::Debug for Map {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.nodes)
+    }
+}
+
+struct Line {
+    loc1: Point,
+    loc2: Point,
+}
+
+impl Line {
+    pub fn new(loc1: Point, loc2: Point) -> Self {
+        Self { loc1, loc2 }
+    }
+
+    fn find_antinodes(&self, rows: i64, cols: i64, only_once: bool) -> HashSet<Point> {
+        let in_range =
+            |loc: &Point| loc.row >= 0 && loc.row < rows && loc.col >= 0 && loc.col < cols;
+        let diff = self.loc2 - self.loc1;
+
+        if only_once {
+            return [self.loc1 - diff, self.loc2 + diff]
+                .into_iter()
+                .filter(in_range)
+                .collect();
+        }
+
+        // Every grid point on the line, not just the multiples of the
+        // distance between the antennas.
+        let divisor = math::gcd(diff.row, diff.col);
+        let step = Point::new(diff.row / divisor, diff.col / divisor);
+
+        let mut antinodes = HashSet::new();
+        for step in [step, -step] {
+            let mut loc = self.loc1;
+            while in_range(&loc) {
+                antinodes.insert(loc);
+                loc += step;
+            }
+        }
+        antinodes
+    }
+}
+
+impl Map {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        Ok(Map {
+            nodes: Grid::parse(input, |c| {
+                (c == '.' || c.is_ascii_alphanumeric()).then_some(c)
+            })?,
+        })
+    }
+
+    fn group_nodes(&self) -> HashMap<char, Vec<Point>> {
+        let mut groups: HashMap<char, Vec<Point>> = HashMap::new();
+
+        for (pos, ch) in self.nodes.iter() {
+            if *ch != '.' {
+                groups.entry(*ch).or_default().push(pos.into());
+            }
+        }
+        groups
+    }
+
+    pub fn update_antinodes(&mut self, only_once: bool) {
+        let rows = self.nodes.height() as i64;
+        let cols = self.nodes.width() as i64;
+
+        let groups = self.group_nodes();
+        for (_key, group) in groups.iter() {
+            for perm in group.iter().permutations(2).unique() {
+                let line = Line::new(*perm[0], *perm[1]);
+                let to_be_updated = line.find_antinodes(rows, cols, only_once);
+                for node in to_be_updated {
+                    self.nodes[node.to_pos().unwrap()] = '#';
+                }
+            }
+        }
+    }
+
+    pub fn antinodes(&self, count_all: bool) -> i32 {
+        self.nodes
+            .find_all(|&c| if count_all { c != '.' } else { c == '#' })
+            .count() as i32
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Parsed = Map;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(Map::new(input)?)
+    }
+    fn part1(map: &Self::Parsed) -> Answer {
+        part1(map).into()
+    }
+    fn part2(map: &Self::Parsed) -> Answer {
+        part2(map).into()
+    }
+}
+
+fn part1(map: &Map) -> i32 {
+    let mut map = map.clone();
+    map.update_antinodes(true);
+    map.antinodes(false)
+}
+
+fn part2(map: &Map) -> i32 {
+    let mut map = map.clone();
+    map.update_antinodes(false);
+    map.antinodes(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_reports_bad_cell() {
+        let e = Map::new("..a.\n.#..").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 2));
+    }
+}
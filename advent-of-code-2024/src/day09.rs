@@ -1,8 +1,9 @@
-use advent_of_code_2024::read_from_file;
+use aoc_core::{Answer, ParseError, Solution};
 
 const FREE: i32 = -1;
 
-struct Blocks {
+#[derive(Clone, Debug)]
+pub struct Blocks {
     ids: Vec<i32>,
 }
 
@@ -23,24 +24,23 @@ impl From<&Blocks> for String {
 }
 
 impl Blocks {
-    pub fn new(input: &str) -> Self {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
         let mut id = 0;
-        let vals = input
-            .chars()
-            .enumerate()
-            .flat_map(|(idx, ch)| {
-                let num_blocks: u32 = ch.to_digit(10).unwrap();
-                if idx % 2 == 0 {
-                    let cur_id = id;
-                    id += 1;
-                    std::iter::repeat(cur_id).take(num_blocks as usize)
-                } else {
-                    std::iter::repeat(FREE).take(num_blocks as usize)
-                }
-            })
-            .collect();
+        let mut vals = Vec::new();
+        for (idx, (start, ch)) in input.trim().char_indices().enumerate() {
+            let Some(num_blocks) = ch.to_digit(10) else {
+                let text = &input.trim()[start..start + ch.len_utf8()];
+                return Err(ParseError::at(input, text, "a digit"));
+            };
+            if idx % 2 == 0 {
+                vals.extend(std::iter::repeat_n(id, num_blocks as usize));
+                id += 1;
+            } else {
+                vals.extend(std::iter::repeat_n(FREE, num_blocks as usize));
+            }
+        }
 
-        Self { ids: vals }
+        Ok(Self { ids: vals })
     }
 
     fn move_block(&mut self, from: usize, to: usize) {
@@ -61,11 +61,11 @@ impl Blocks {
             idx += 1;
         }
 
-        return if self.is_valid_idx(idx) {
+        if self.is_valid_idx(idx) {
             Some(idx)
         } else {
             None
-        };
+        }
     }
 
     fn find_next_valid_block(&self, idx: usize) -> Option<usize> {
@@ -76,17 +76,19 @@ impl Blocks {
             idx -= 1;
         }
 
-        return if self.is_valid_idx(idx) {
+        if self.is_valid_idx(idx) {
             Some(idx)
         } else {
             None
-        };
+        }
     }
 
 
     fn zip_blocks(&mut self) {
         let mut write_idx = 0;
-        let mut read_idx = self.ids.len() - 1;
+        let Some(mut read_idx) = self.ids.len().checked_sub(1) else {
+            return;
+        };
 
         while write_idx < read_idx && write_idx < self.ids.len() {
             if let Some(idx) = self.find_next_free_block(write_idx) {
@@ -106,7 +108,6 @@ impl Blocks {
             }
 
             self.move_block(read_idx, write_idx);
-            // println!("{}", String::from(self.deref()));
         }
     }
 
@@ -133,7 +134,9 @@ impl Blocks {
 
     fn zip_blocks_by_id(&mut self) {
         let mut write_idx = 0;
-        let mut read_idx = self.ids.len() - 1;
+        let Some(mut read_idx) = self.ids.len().checked_sub(1) else {
+            return;
+        };
 
         while write_idx < read_idx && write_idx < self.ids.len() {
             if let Some(idx) = self.find_next_valid_block(read_idx) {
@@ -166,9 +169,6 @@ impl Blocks {
                     read_idx -= 1;
                     write_idx += 1;
                 }
-
-                // println!("{}", String::from(self.deref()));
-                // println!("write_idx={write_idx}, read_idx={read_idx}");
             } else {
                 if read_idx < num_blocks {
                     break;
@@ -194,34 +194,42 @@ impl Blocks {
     }
 }
 
-fn part1(input: &str) -> i64 {
-    let mut blockmap = Blocks::new(input);
+fn part1(blocks: &Blocks) -> i64 {
+    let mut blockmap = blocks.clone();
     blockmap.zip_blocks();
     blockmap.checksum()
 }
 
-fn part2(input: &str) -> i64 {
-    let mut blockmap = Blocks::new(input);
+fn part2(blocks: &Blocks) -> i64 {
+    let mut blockmap = blocks.clone();
     blockmap.zip_blocks_by_id();
     blockmap.checksum()
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let input = read_from_file("inputs/day09.txt")?;
-    println!("part1={}", part1(&input));
-    println!("part2={}", part2(&input)); // 7389006017011 too high
-    Ok(())
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Parsed = Blocks;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(Blocks::new(input)?)
+    }
+    fn part1(blocks: &Self::Parsed) -> Answer {
+        part1(blocks).into()
+    }
+    fn part2(blocks: &Self::Parsed) -> Answer {
+        // 7389006017011 too high
+        part2(blocks).into()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const INPUT: &str = "2333133121414131402";
-
     #[test]
     fn test_utils() {
-        let blockmap = Blocks::new(INPUT);
+        let blockmap = Blocks::new(&crate::read_example(9, "1").unwrap()).unwrap();
         let result: String = (&blockmap).into();
 
         assert_eq!(
@@ -231,12 +239,14 @@ mod tests {
     }
 
     #[test]
-    fn test_part1() {
-        assert_eq!(part1(INPUT), 1928);
-    }
-
-    #[test]
-    fn test_part2() {
-        assert_eq!(part2(INPUT), 2858);
+    fn test_bad_disk_map() {
+        assert!(Blocks::new("2333133121414131402\n").is_ok());
+        let e = Blocks::new("23\n31").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 3));
+        let e = Blocks::new("23331x3121414131402").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 6));
+
+        let empty = Blocks::new("000").unwrap();
+        assert_eq!((part1(&empty), part2(&empty)), (0, 0));
     }
 }
@@ -0,0 +1,76 @@
+use aoc_core::grid::Pos;
+use aoc_core::search;
+use aoc_core::{Answer, Grid, ParseError, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Map;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(Map::new(input)?)
+    }
+    fn part1(map: &Self::Parsed) -> Answer {
+        part1(map).into()
+    }
+    fn part2(map: &Self::Parsed) -> Answer {
+        part2(map).into()
+    }
+}
+
+fn part1(map: &Map) -> i32 {
+    map.trailheads()
+        .map(|start| map.score(start))
+        .sum::<usize>() as i32
+}
+
+fn part2(map: &Map) -> i32 {
+    map.trailheads()
+        .map(|start| map.rating(start))
+        .sum::<usize>() as i32
+}
+
+pub struct Map {
+    heights: Grid<i32>,
+}
+
+impl Map {
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let heights = Grid::parse(input, |c| c.to_digit(10).map(|d| d as i32))?;
+        Ok(Self { heights })
+    }
+
+    fn trailheads(&self) -> impl Iterator<Item = Pos> + '_ {
+        self.heights.find_all(|&height| height == 0)
+    }
+
+    fn uphill(&self, pos: Pos) -> Vec<Pos> {
+        self.heights
+            .neighbours4(pos)
+            .filter(|&next| self.heights[next] == self.heights[pos] + 1)
+            .collect()
+    }
+
+    fn is_peak(&self, pos: Pos) -> bool {
+        self.heights[pos] == 9
+    }
+
+    /// Number of peaks reachable from `start`.
+    fn score(&self, start: Pos) -> usize {
+        search::bfs_distances(start, |&pos| self.uphill(pos))
+            .into_keys()
+            .filter(|&pos| self.is_peak(pos))
+            .count()
+    }
+
+    /// Number of distinct trails from `start` to any peak. Every trail climbs
+    /// one step at a time, so they are all shortest paths.
+    fn rating(&self, start: Pos) -> usize {
+        search::all_shortest_paths(
+            start,
+            |&pos| self.uphill(pos).into_iter().map(|next| (next, 1)),
+            |&pos| self.is_peak(pos),
+        )
+        .len()
+    }
+}
@@ -0,0 +1,60 @@
+use aoc_core::digits;
+use aoc_core::{Answer, Memo, Solution, Span};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Vec<u64>;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(Span::new(input).list(" ")?)
+    }
+    fn part1(stones: &Self::Parsed) -> Answer {
+        part1(stones).into()
+    }
+    fn part2(stones: &Self::Parsed) -> Answer {
+        part2(stones).into()
+    }
+}
+
+fn blink(stone: u64) -> Vec<u64> {
+    let num_digits = digits::count(stone);
+    if stone == 0 {
+        vec![1]
+    } else if num_digits.is_multiple_of(2) {
+        let (left, right) = digits::split(stone, num_digits / 2);
+        vec![left, right]
+    } else {
+        vec![stone * 2024]
+    }
+}
+
+/// How many stones `stone` turns into after `num_times` blinks. Stones never
+/// affect each other, so each one is counted on its own.
+fn stones_after(memo: &mut Memo<(u64, i32), usize>, stone: u64, num_times: i32) -> usize {
+    memo.get((stone, num_times), |rec, &(stone, num_times)| {
+        if num_times == 0 {
+            return 1;
+        }
+        blink(stone)
+            .into_iter()
+            .map(|next| rec.call((next, num_times - 1)))
+            .sum()
+    })
+}
+
+fn blinks(stones: &[u64], num_times: i32) -> usize {
+    let mut memo = Memo::new();
+    stones
+        .iter()
+        .map(|&stone| stones_after(&mut memo, stone, num_times))
+        .sum()
+}
+
+fn part1(stones: &[u64]) -> usize {
+    blinks(stones, 25)
+}
+
+fn part2(stones: &[u64]) -> usize {
+    blinks(stones, 75)
+}
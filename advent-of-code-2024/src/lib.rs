@@ -1,19 +1,18 @@
-use std::fs::File;
-use std::io::Read;
+pub use aoc_core::is_same_vec;
 
-pub fn read_from_file(filename: &str) -> Result<String, std::io::Error> {
-    let mut file = File::open(filename).unwrap();
-    let mut buffer = String::new();
-    file.read_to_string(&mut buffer)?;
+pub const YEAR: u16 = 2024;
+pub const CRATE_DIR: &str = env!("CARGO_MANIFEST_DIR");
 
-    Ok(buffer)
-}
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
 
-pub fn is_same_vec(vec1: &Vec<i32>, vec2: &Vec<i32>) -> bool {
-    let matching = vec1
-        .iter()
-        .zip(vec2.iter())
-        .filter(|&(a, b)| a == b)
-        .count();
-    matching == vec1.len() && matching == vec2.len()
-}
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
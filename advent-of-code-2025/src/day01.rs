@@ -0,0 +1,160 @@
+use aoc_core::math::Mod;
+use aoc_core::{Answer, ParseError, Solution, Span};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed = Vec<Instruction>;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_input(input)?)
+    }
+    fn part1(instructions: &Self::Parsed) -> Answer {
+        // 1036
+        part1(instructions).into()
+    }
+    fn part2(instructions: &Self::Parsed) -> Answer {
+        part2(instructions).into()
+    }
+}
+
+#[derive(Debug)]
+enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    dir: Direction,
+    count: i64,
+}
+
+
+/// One `L` or `R` turn per line, followed by how many clicks it goes.
+fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    Span::new(input).lines_with(|line| {
+        let (dir, count) = if let Ok(count) = line.strip_prefix("L") {
+            (Direction::Left, count)
+        } else if let Ok(count) = line.strip_prefix("R") {
+            (Direction::Right, count)
+        } else {
+            return Err(line.error("`L` or `R` and a number of clicks"));
+        };
+        Ok(Instruction {
+            dir,
+            count: count.parse::<u32>()?.into(),
+        })
+    })
+}
+
+type Dial = Mod<100>;
+
+struct Value {
+    val: Dial,
+}
+
+impl Value {
+    fn new() -> Self {
+        Value::new_test(50)
+    }
+
+    fn new_test(init_val: u64) -> Self {
+        Self {
+            val: Dial::new(init_val),
+        }
+    }
+
+    #[allow(dead_code)]
+    fn turn_bruteforce(&mut self, inst: &Instruction) -> i64 {
+        let click = match inst.dir {
+            Direction::Left => -Dial::new(1),
+            Direction::Right => Dial::new(1),
+        };
+
+        let mut zero = 0;
+        for _ in 0..inst.count {
+            self.val += click;
+            if self.val == Dial::new(0) {
+                zero += 1;
+            }
+        }
+
+        zero
+    }
+
+    fn execute(&mut self, inst: &Instruction) -> i64 {
+        // How far the dial is past zero, looking back against the turn. A
+        // turn that covers that plus a full round stops on zero again.
+        let (past_zero, clicks) = match inst.dir {
+            Direction::Left => ((-self.val).value() as i64, -inst.count),
+            Direction::Right => (self.val.value() as i64, inst.count),
+        };
+
+        self.val += Dial::from(clicks);
+        (past_zero + inst.count) / 100
+    }
+}
+
+fn part1(input: &[Instruction]) -> i64 {
+    let mut current = Value::new();
+    input.iter().map(|inst| {
+        let _ = current.execute(inst);
+        current.val
+    }).filter(|val| *val == Dial::new(0)).count() as i64
+}
+
+fn part2(input: &[Instruction]) -> i64 {
+    let mut current = Value::new();
+    input.iter().map(|inst| {
+        current.execute(inst)
+    }).sum::<i64>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_counting() {
+        let mut val = Value::new();
+
+        // 50 + 1000  => 50 (passed zero 1050 / 100  = 10)
+        assert_eq!(val.execute(&Instruction {
+            dir: Direction::Right,
+            count: 1000
+        }), 10);
+
+        // 50 - 650 = -600 => 0 (passed zero 600 / 100 = 6) + 1
+        assert_eq!(val.execute(&Instruction {
+            dir: Direction::Left,
+            count: 650
+        }), 7);
+
+        // 35 + 765 = 800
+        let mut val = Value::new_test(35);
+        assert_eq!(val.execute(&Instruction { dir: Direction::Right, count: 765 }), 8);
+    }
+    #[test]
+    fn test_matches_bruteforce() {
+        for init_val in [0, 1, 50, 99] {
+            for count in [0, 1, 99, 100, 101, 250] {
+                for dir in [Direction::Left, Direction::Right] {
+                    let inst = Instruction { dir, count };
+                    let mut fast = Value::new_test(init_val);
+                    let mut slow = Value::new_test(init_val);
+                    assert_eq!(fast.execute(&inst), slow.turn_bruteforce(&inst), "{inst:?} from {init_val}");
+                    assert_eq!(fast.val, slow.val);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let e = parse_input("L68\nL30\nU48").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 1));
+        let e = parse_input("L68\nR-5").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 2));
+    }
+}
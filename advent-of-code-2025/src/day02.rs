@@ -0,0 +1,188 @@
+use aoc_core::digits;
+use aoc_core::{Answer, ParseError, Solution, Span};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed = Vec<Range>;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_input(input)?)
+    }
+    fn part1(range: &Self::Parsed) -> Answer {
+        part1(range).into()
+    }
+    fn part2(range: &Self::Parsed) -> Answer {
+        part2(range).into()
+    }
+}
+
+#[derive(Debug)]
+pub struct Range {
+    start: u64,
+    end: u64,
+}
+
+fn smallest_num(n: u32) -> u64 {
+    // 10 ^ n
+    let sub = if n == 1 {1} else {0};
+    digits::pow10::<u64>(n - 1) - sub
+
+}
+
+fn largest_num(n: u32) -> u64 {
+    // 10 ^ (n+1) - 1
+    digits::pow10::<u64>(n) - 1
+}
+
+fn is_duplicate(val: u64) -> bool {
+    digits::repeated_block(val, 2).is_some()
+}
+
+fn is_repeating(val: u64) -> bool {
+    digits::is_repetition(val)
+}
+
+impl Range {
+    fn find_duplicate_ids(&self) -> Vec<u64> {
+        let mut invalid_ids = Vec::new();
+        let start_len = digits::count(self.start);
+        let end_len = digits::count(self.end);
+
+        for i in start_len..end_len+1 {
+            if i % 2 != 0 {
+                continue;
+            }
+            let start_num = if i == start_len { self.start } else { smallest_num(i) };
+            let end_num = if i == end_len { self.end } else { largest_num(i) };
+
+            for val in start_num..end_num+1 {
+                if is_duplicate(val) {
+                    invalid_ids.push(val);
+                }
+            }
+
+        }
+
+        invalid_ids
+    }
+
+    fn find_repeating_ids(&self) -> Vec<u64> {
+        let mut invalid_ids = Vec::new();
+        let start_len = digits::count(self.start);
+        let end_len = digits::count(self.end);
+
+        for i in start_len..end_len+1 {
+            let start_num = if i == start_len { self.start } else { smallest_num(i) };
+            let end_num = if i == end_len { self.end } else { largest_num(i) };
+
+            for val in start_num..end_num+1 {
+                if is_repeating(val) {
+                    invalid_ids.push(val);
+                }
+            }
+
+        }
+
+        invalid_ids
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Range>, ParseError> {
+    Span::new(input).split_with(",", |range| {
+        let (start, end) = range.pair("-")?;
+        Ok(Range { start, end })
+    })
+}
+
+fn part1(range: &[Range]) -> u64 {
+    range.iter().map(|r| {
+        r.find_duplicate_ids().iter().copied().sum::<u64>()
+    }).sum::<u64>()
+}
+
+fn part2(range: &[Range]) -> u64 {
+    range.iter().map(|r| {
+        r.find_repeating_ids().iter().copied().sum::<u64>()
+    }).sum::<u64>()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_util() {
+        assert_eq!(smallest_num(1), 0);
+        assert_eq!(largest_num(1), 9);
+
+        assert_eq!(smallest_num(2), 10);
+        assert_eq!(largest_num(2), 99);
+
+        assert_eq!(smallest_num(3), 100);
+        assert_eq!(largest_num(3), 999);
+    }
+
+    #[test]
+    fn test_find_duplicate_ids() {
+        let range = Range {
+            start: 11,
+            end: 22,
+        };
+        let invalid_ids = range.find_duplicate_ids();
+        assert_eq!(invalid_ids.len(), 2);
+        assert!(invalid_ids.contains(&11));
+        assert!(invalid_ids.contains(&22));
+
+        let range = Range {
+            start: 95,
+            end: 115,
+        };
+        let invalid_ids = range.find_duplicate_ids();
+        assert!(invalid_ids.contains(&99));
+    }
+
+    #[test]
+    fn test_find_repeating_ids() {
+        let range = Range {
+            start: 95,
+            end: 115,
+        };
+        let invalid_ids = range.find_repeating_ids();
+        assert!(invalid_ids.contains(&99));
+        assert!(invalid_ids.contains(&111));
+
+        let range =  Range {
+            start: 998,
+            end: 1012,
+        };
+        let invalid_ids = range.find_repeating_ids();
+        assert!(invalid_ids.contains(&999));
+        assert!(invalid_ids.contains(&1010));
+        
+        let range =  Range {
+            start: 1188511880,
+            end: 1188511890,
+        };
+        let invalid_ids = range.find_repeating_ids();
+        assert_eq!(invalid_ids.len(), 1);
+        assert!(invalid_ids.contains(&1188511885));
+
+        let range =  Range {
+            start: 1698522,
+            end: 1698528,
+        };
+        let invalid_ids = range.find_repeating_ids();
+        assert_eq!(invalid_ids.len(), 0);
+
+        let range =  Range {
+            start: 824824821,
+            end: 824824827,
+        };
+        let invalid_ids = range.find_repeating_ids();
+        assert_eq!(invalid_ids.len(), 1);
+        assert!(invalid_ids.contains(&824824824));
+
+    }
+
+}
\ No newline at end of file
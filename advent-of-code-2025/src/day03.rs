@@ -0,0 +1,87 @@
+use aoc_core::{Answer, Grid, ParseError, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = Grid<i64>;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_banks(input)?)
+    }
+    fn part1(banks: &Self::Parsed) -> Answer {
+        part1(banks).into()
+    }
+    fn part2(banks: &Self::Parsed) -> Answer {
+        Answer::or_error(part2(banks), "banks need at least 12 batteries")
+    }
+}
+
+/// One row of battery joltages per bank.
+fn parse_banks(input: &str) -> Result<Grid<i64>, ParseError> {
+    Grid::parse(input, |ch| ch.to_digit(10).map(i64::from))
+}
+
+fn part1(banks: &Grid<i64>) -> i64 {
+    banks.rows().map(find_largest_joltage).sum()
+}
+
+/// `None` if the banks have fewer than twelve batteries.
+fn part2(banks: &Grid<i64>) -> Option<i64> {
+    banks.rows().map(find_twelve_sum).sum()
+}
+
+fn find_largest_joltage(bank: &[i64]) -> i64 {
+    // bruteforce
+    let mut largest: i64 = 0;
+    for i in 0..bank.len() {
+        for j in i+1..bank.len() {
+            let new_sum = bank[i] * 10 + bank[j];
+            if new_sum > largest {
+                largest = new_sum;
+            }
+        }
+    }
+
+    largest
+}
+
+fn find_twelve_sum(bank: &[i64]) -> Option<i64> {
+    // stack + greedy
+    let mut to_remove = bank.len().checked_sub(12)?;
+    let mut stack = Vec::new();
+
+    for &cur_num in bank {
+        while to_remove > 0 && stack.last().is_some_and(|&last_num| last_num < cur_num) {
+            stack.pop();
+            to_remove -= 1;
+        }
+        stack.push(cur_num);
+    }
+
+    Some(stack.iter().take(12).fold(0, |num, digit| num*10 + digit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_largest_joltage() {
+        let banks = parse_banks("987654321111111\n811111111111119").unwrap();
+        assert_eq!(find_largest_joltage(banks.row(0)), 98);
+        assert_eq!(find_largest_joltage(banks.row(1)), 89);
+    }
+
+    #[test]
+    fn test_parse_banks_reports_bad_battery() {
+        let e = parse_banks("987654321111111\n8111111x1111119").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 8));
+    }
+
+    #[test]
+    fn test_short_banks() {
+        let banks = parse_banks("9876\n8119").unwrap();
+        assert_eq!(part1(&banks), 98 + 89);
+        assert_eq!(part2(&banks), None);
+    }
+}
\ No newline at end of file
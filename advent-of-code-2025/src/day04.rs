@@ -0,0 +1,87 @@
+use aoc_core::grid::Pos;
+use aoc_core::{Answer, Grid, ParseError, Solution};
+use std::collections::HashSet;
+
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Parsed = Map;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(Map::new(input)?)
+    }
+    fn part1(map: &Self::Parsed) -> Answer {
+        part1(map).into()
+    }
+    fn part2(map: &Self::Parsed) -> Answer {
+        let mut map = map.clone();
+        part2(&mut map).into()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    map: Grid<char>,
+}
+
+impl Map {
+    /// A grid of `@` paper rolls and `.` floor.
+    fn new(input: &str) -> Result<Self, ParseError> {
+        let map = Grid::parse(input, |c| matches!(c, '@' | '.').then_some(c))?;
+        Ok(Map { map })
+    }
+
+    fn adjacent_rolls(&self, pos: Pos) -> i32 {
+        self.map
+            .neighbours8(pos)
+            .filter(|&adjacent| self.map[adjacent] == '@')
+            .count() as i32
+    }
+
+    fn is_accessible(&self, pos: Pos) -> bool {
+        self.map.get(pos) == Some(&'@') && self.adjacent_rolls(pos) < 4
+    }
+}
+
+fn part1(map: &Map) -> i64 {
+    map.map
+        .positions()
+        .filter(|&pos| map.is_accessible(pos))
+        .count() as i64
+}
+
+fn part2(map: &mut Map) -> i64 {
+    let mut ret = 0;
+    loop {
+        let accessible: HashSet<Pos> = map
+            .map
+            .positions()
+            .filter(|&pos| map.is_accessible(pos))
+            .collect();
+        if accessible.is_empty() {
+            break;
+        }
+
+        ret += accessible.len();
+
+        accessible.iter().for_each(|&pos| {
+            map.map[pos] = 'x';
+        });
+    }
+
+    ret as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let e = Map::new("..@@.\n@@x.@").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
+        let e = Map::new("..@@.\n@@.").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+    }
+}
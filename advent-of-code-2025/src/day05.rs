@@ -0,0 +1,83 @@
+use aoc_core::{Answer, Interval, IntervalSet, ParseError, Solution, Span};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed = (Ingredients, Vec<i64>);
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_input(input)?)
+    }
+    fn part1((ingredients, targets): &Self::Parsed) -> Answer {
+        part1(ingredients, targets).into()
+    }
+    fn part2((ingredients, _): &Self::Parsed) -> Answer {
+        part2(ingredients).into()
+    }
+}
+
+fn part1(ingredients: &Ingredients, targets: &[i64]) -> i64{
+    targets.iter().filter(|val| {
+        ingredients.is_fresh(**val)
+    }).count() as i64
+}
+
+fn part2(ingredients: &Ingredients) -> i64 {
+    ingredients.count()
+}
+#[derive(Default, Debug, Clone)]
+pub struct Ingredients {
+    fresh: IntervalSet<i64>,
+}
+
+impl Ingredients {
+    /// One `first-last` range of fresh ids per line.
+    fn parse(ranges: Span) -> Result<Self, ParseError> {
+        let fresh = ranges.lines_with(|line| {
+            let (first, last): (i64, i64) = line.pair("-")?;
+            if first > last || last == i64::MAX {
+                return Err(line.error("a range from an id to one no smaller"));
+            }
+            Ok(Interval::inclusive(first, last))
+        })?;
+        Ok(Ingredients {
+            fresh: fresh.into_iter().collect(),
+        })
+    }
+    fn is_fresh(&self, val: i64) -> bool {
+        self.fresh.contains(val)
+    }
+    fn count(&self) -> i64 {
+        self.fresh.len()
+    }
+}
+
+/// The fresh ranges and the available ids, separated by a blank line.
+fn parse_input(input: &str) -> Result<(Ingredients, Vec<i64>), ParseError> {
+    let (ranges, targets) = Span::new(input).split_once("\n\n")?;
+    Ok((Ingredients::parse(ranges)?, build_targets(targets)?))
+}
+
+fn build_targets(targets: Span) -> Result<Vec<i64>, ParseError> {
+    targets.lines_with(Span::parse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error() {
+        let (ingredients, targets) = parse_input("3-5\n10-14\n\n1\n5").unwrap();
+        assert_eq!(part1(&ingredients, &targets), 1);
+
+        let e = parse_input("3-5\n10:14\n\n1").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        let e = parse_input("3-5\n14-10\n\n1").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        let e = parse_input("3-5\n\n1\n\n5").unwrap_err();
+        assert_eq!((e.line, e.column), (4, 1));
+        let e = parse_input("3-5\n10-14").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
+    }
+}
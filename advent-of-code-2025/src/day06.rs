@@ -0,0 +1,166 @@
+use aoc_core::{Answer, ParseError, Solution, Span};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed = Worksheet;
+
+    fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+        Ok(parse_worksheet(input)?)
+    }
+    fn part1(worksheet: &Self::Parsed) -> Answer {
+        part1(worksheet).into()
+    }
+    fn part2(worksheet: &Self::Parsed) -> Answer {
+        part2(worksheet).into()
+    }
+}
+
+/// The worksheet read both ways: numbers written along the rows, and
+/// numbers written down the columns.
+#[derive(Debug)]
+pub struct Worksheet {
+    by_rows: Problem,
+    by_columns: Problem,
+}
+
+fn parse_worksheet(input: &str) -> Result<Worksheet, ParseError> {
+    Ok(Worksheet {
+        by_rows: Problem::from(input)?,
+        by_columns: Problem::from_right_to_left(input)?,
+    })
+}
+
+fn part1(worksheet: &Worksheet) -> i64 {
+    worksheet.by_rows.solve()
+}
+
+fn part2(worksheet: &Worksheet) -> i64 {
+    worksheet.by_columns.solve()
+}
+
+#[derive(Default, Debug)]
+struct Problem {
+    numbers: Vec<Vec<i64>>,
+    operators: Vec<char>,
+    cols: usize,
+    rows: usize,
+}
+
+impl Problem {
+    /// The operators from the last line, with room for the numbers of
+    /// each problem.
+    fn parse_operator(input: &str) -> Result<Problem, ParseError> {
+        let mut problem = Problem::default();
+        let lines: Vec<Span> = Span::new(input).lines().collect();
+        let Some(operators) = lines.last() else {
+            return Err(ParseError::at(input, "", "a line of operators"));
+        };
+        for op in operators.split(" ") {
+            match op.as_str() {
+                "+" => problem.operators.push('+'),
+                "*" => problem.operators.push('*'),
+                _ => return Err(op.error("`+` or `*`")),
+            }
+        }
+        problem.cols = problem.operators.len();
+        problem.rows = lines.len() - 1;
+
+        problem.numbers.resize(problem.cols, Vec::new());
+
+        Ok(problem)
+    }
+
+    fn from(input: &str) -> Result<Problem, ParseError> {
+        let mut problem = Problem::parse_operator(input)?;
+
+        for line in Span::new(input).lines().take(problem.rows) {
+            let cols: Vec<i64> = line.list(" ")?;
+            if cols.len() != problem.cols {
+                return Err(line.error(format!("{} numbers", problem.cols)));
+            }
+            cols.into_iter().enumerate().for_each(|(idx, num)| {
+                problem.numbers[idx].push(num);
+            });
+        }
+
+        Ok(problem)
+    }
+
+    /// Reads each number down a column of characters, top digit first. A
+    /// column of nothing but spaces separates two problems.
+    fn from_right_to_left(input: &str) -> Result<Self, ParseError> {
+        let mut problem = Problem::parse_operator(input)?;
+        let lines: Vec<&str> = input.lines().take(problem.rows).collect();
+
+        let mut col_idx = 0;
+        let line_len = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+
+        for char_idx in 0..line_len {
+            let mut number = None;
+            for line in &lines {
+                let text = line.get(char_idx..char_idx + 1).unwrap_or(line);
+                match line.as_bytes().get(char_idx) {
+                    None | Some(b' ') => {}
+                    Some(digit) if digit.is_ascii_digit() => {
+                        if col_idx == problem.cols {
+                            let expected = format!("{} problems", problem.cols);
+                            return Err(ParseError::at(input, text, expected));
+                        }
+                        let digit = (digit - b'0') as i64;
+                        number = Some(number.unwrap_or(0) * 10 + digit);
+                    }
+                    Some(_) => return Err(ParseError::at(input, text, "a digit")),
+                }
+            }
+
+            match number {
+                Some(number) => problem.numbers[col_idx].push(number),
+                None => col_idx += 1,
+            }
+        }
+
+        Ok(problem)
+    }
+    fn solve(&self) -> i64 {
+        self.operators.iter().enumerate().map(|(idx, op)| {
+            let initial_value = if *op == '+' { 0 } else if *op == '*' { 1 } else { panic!("can't reach here")};
+            self.numbers[idx].iter().fold(initial_value, |acc, x| {
+                match op {
+                    '+' => acc + x,
+                    '*' => acc * x,
+                    _ => panic!("not expected")
+                }
+            })
+        }).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_by_columns() {
+        let input = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ";
+        let worksheet = parse_worksheet(input).unwrap();
+        assert_eq!(worksheet.by_rows.numbers[0], vec![123, 45, 6]);
+        assert_eq!(worksheet.by_columns.numbers[0], vec![1, 24, 356]);
+        assert_eq!(worksheet.by_columns.numbers[3], vec![623, 431, 4]);
+    }
+
+    #[test]
+    fn test_parse_worksheet_reports_bad_cells() {
+        let e = parse_worksheet("1 2\n3 4\n+ -").unwrap_err();
+        assert_eq!((e.line, e.column), (3, 3));
+
+        let e = parse_worksheet("1 2\n3\n+ *").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+
+        let e = parse_worksheet("1 2\n3 x\n+ *").unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
+
+        let e = parse_worksheet("").unwrap_err();
+        assert_eq!((e.line, e.column), (1, 1));
+    }
+}
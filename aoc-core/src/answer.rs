@@ -0,0 +1,94 @@
+use std::fmt;
+
+/// The result of solving one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+    Unsolved,
+    /// The part ran, but the input has no answer for it, such as a maze
+    /// with no way out. The message says why.
+    Error(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Unsolved => write!(f, "unsolved"),
+            Answer::Error(message) => write!(f, "error: {}", message),
+        }
+    }
+}
+
+impl Answer {
+    /// `value` as an answer, or an [`Answer::Error`] saying `why` there is
+    /// none.
+    pub fn or_error<T: Into<Answer>>(value: Option<T>, why: &str) -> Answer {
+        value.map_or_else(|| Answer::Error(why.to_string()), Into::into)
+    }
+}
+
+macro_rules! impl_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        match i128::try_from(value) {
+            Ok(n) => Answer::Number(n),
+            Err(_) => Answer::Text(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_numbers() {
+        assert_eq!(Answer::from(42i32), Answer::Number(42));
+        assert_eq!(Answer::from(42u64), Answer::Number(42));
+        assert_eq!(Answer::from(-3i64), Answer::Number(-3));
+        assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(143).to_string(), "143");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+        assert_eq!(
+            Answer::or_error(None::<u32>, "no way out").to_string(),
+            "error: no way out"
+        );
+        assert_eq!(
+            Answer::or_error(Some(7u32), "no way out"),
+            Answer::Number(7)
+        );
+    }
+}
@@ -0,0 +1,159 @@
+//! Code generation for the year crates' build scripts.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+use crate::example::Example;
+
+/// Finds every `src/dayN.rs` of the crate being built and writes `days.rs`
+/// to `OUT_DIR`, with a `puzzles()` function that registers each module's
+/// `DayN` solution for `crate::YEAR`, and the `read_input` and
+/// `read_example` helpers the days' tests load their inputs with. Every
+/// example fixture with expected answers (see [`crate::example`]) also gets
+/// a `#[test]` in a `example_tests` module.
+///
+/// The year's `lib.rs` declares a `pub mod dayN;` per day, so that rustfmt
+/// and other tools see the modules, and pulls the rest in with
+/// `include!(concat!(env!("OUT_DIR"), "/days.rs"));`. A day file without
+/// its `mod` declaration fails to compile rather than going missing.
+pub fn register_days() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("CARGO_MANIFEST_DIR is not set");
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is not set");
+    let src = Path::new(&manifest_dir).join("src");
+    println!("cargo:rerun-if-changed={}", src.display());
+    let examples = Path::new(&manifest_dir).join("examples");
+    if examples.is_dir() {
+        println!("cargo:rerun-if-changed={}", examples.display());
+    }
+
+    let mut days = Vec::new();
+    for entry in fs::read_dir(&src).expect("failed to read src directory") {
+        let path = entry.expect("failed to read src directory entry").path();
+        let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if let Some(day) = day_number(file_name) {
+            let module = file_name.trim_end_matches(".rs").to_string();
+            days.push((day, module));
+        }
+    }
+    days.sort();
+
+    let mut code = String::new();
+    writeln!(
+        code,
+        "pub fn puzzles() -> Vec<aoc_core::Puzzle> {{\n    vec!["
+    )
+    .unwrap();
+    for (day, module) in &days {
+        writeln!(
+            code,
+            "        aoc_core::Puzzle::new::<{}::{}>(crate::YEAR, {}, crate::CRATE_DIR),",
+            module,
+            struct_name(module),
+            day
+        )
+        .unwrap();
+    }
+    writeln!(code, "    ]\n}}").unwrap();
+    code.push_str(READERS);
+
+    writeln!(code, "\n#[cfg(test)]\nmod example_tests {{").unwrap();
+    for (day, module) in &days {
+        let examples = Example::all(*day, &manifest_dir).expect("failed to read examples");
+        for example in examples.iter().filter(|example| example.has_expected()) {
+            writeln!(
+                code,
+                "    #[test]\n    fn {}_example_{}() {{\n        aoc_core::example::check(\n            &aoc_core::Puzzle::new::<super::{}::{}>(crate::YEAR, {}, crate::CRATE_DIR),\n            {:?},\n        );\n    }}",
+                module,
+                identifier(&example.name),
+                module,
+                struct_name(module),
+                day,
+                example.name
+            )
+            .unwrap();
+        }
+    }
+    writeln!(code, "}}").unwrap();
+
+    fs::write(Path::new(&out_dir).join("days.rs"), code).expect("failed to write days.rs");
+}
+
+/// The input readers of `days.rs`, which only depend on `crate::YEAR` and
+/// `crate::CRATE_DIR`.
+const READERS: &str = r#"
+/// Reads this year's input for `day` through [`aoc_core::Input::resolve`],
+/// so tests find it no matter where they are run from.
+pub fn read_input(day: u8) -> aoc_core::Result<String> {
+    aoc_core::Input::resolve(crate::YEAR, day, crate::CRATE_DIR, None)?.read()
+}
+
+/// Reads fixture `name` of `day` from this crate's `examples/`, for tests
+/// that need an example input itself rather than its answers.
+pub fn read_example(day: u8, name: &str) -> aoc_core::Result<String> {
+    aoc_core::example::Example::find(crate::YEAR, day, crate::CRATE_DIR, name)?
+        .input()
+        .read()
+}
+"#;
+
+/// `day7.rs` and `day07.rs` are day 7; anything else is not a day module.
+fn day_number(file_name: &str) -> Option<u8> {
+    let digits = file_name.strip_prefix("day")?.strip_suffix(".rs")?;
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+fn struct_name(module: &str) -> String {
+    let mut chars = module.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+/// A fixture name as part of a test function name: `edge-case` -> `edge_case`.
+fn identifier(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_number() {
+        assert_eq!(day_number("day7.rs"), Some(7));
+        assert_eq!(day_number("day07.rs"), Some(7));
+        assert_eq!(day_number("day25.rs"), Some(25));
+        assert_eq!(day_number("lib.rs"), None);
+        assert_eq!(day_number("day.rs"), None);
+        assert_eq!(day_number("day07_helpers.rs"), None);
+        assert_eq!(day_number("day07.txt"), None);
+    }
+
+    #[test]
+    fn test_struct_name() {
+        assert_eq!(struct_name("day07"), "Day07");
+        assert_eq!(struct_name("day1"), "Day1");
+    }
+
+    #[test]
+    fn test_identifier() {
+        assert_eq!(identifier("1"), "1");
+        assert_eq!(identifier("Edge-case.2"), "edge_case_2");
+    }
+}
@@ -0,0 +1,159 @@
+//! Finding where a repeated step starts going round in circles.
+//!
+//! A simulation that keeps applying the same step to a finite state must
+//! eventually revisit a state, after which it repeats forever. Knowing where
+//! the loop starts and how long it is answers "what is the state after a
+//! billion steps" without taking them.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The states from the initial one up to the end of the first loop.
+#[derive(Debug, Clone)]
+pub struct Cycle<S> {
+    /// Steps taken before the first state of the loop.
+    pub start: usize,
+    /// Steps it takes to come back to a state within the loop.
+    pub length: usize,
+    states: Vec<S>,
+}
+
+impl<S> Cycle<S> {
+    /// The state after `steps` steps.
+    pub fn state_at(&self, steps: usize) -> &S {
+        &self.states[self.index(steps)]
+    }
+
+    /// The step count before the loop that reaches the same state as
+    /// `steps` steps.
+    pub fn index(&self, steps: usize) -> usize {
+        if steps < self.start {
+            steps
+        } else {
+            self.start + (steps - self.start) % self.length
+        }
+    }
+}
+
+/// Applies `step` until a state comes back, remembering every state on the
+/// way.
+pub fn find_cycle<S: Clone + Hash + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle<S> {
+    let mut seen = HashMap::from([(initial.clone(), 0)]);
+    let mut states = vec![initial];
+    loop {
+        let next = step(&states[states.len() - 1]);
+        if let Some(&start) = seen.get(&next) {
+            return Cycle {
+                start,
+                length: states.len() - start,
+                states,
+            };
+        }
+        seen.insert(next.clone(), states.len());
+        states.push(next);
+    }
+}
+
+/// The state after `steps` steps, stopping as soon as the states repeat.
+pub fn state_after<S: Clone + Hash + Eq>(
+    initial: S,
+    steps: usize,
+    mut step: impl FnMut(&S) -> S,
+) -> S {
+    let mut seen = HashMap::from([(initial.clone(), 0)]);
+    let mut states = vec![initial];
+    while states.len() <= steps {
+        let next = step(&states[states.len() - 1]);
+        if let Some(&start) = seen.get(&next) {
+            let cycle = Cycle {
+                start,
+                length: states.len() - start,
+                states,
+            };
+            return cycle.state_at(steps).clone();
+        }
+        seen.insert(next.clone(), states.len());
+        states.push(next);
+    }
+    states.swap_remove(steps)
+}
+
+/// Brent's algorithm: the `(start, length)` of the loop, keeping only a
+/// couple of states in memory at a time.
+pub fn brent<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> (usize, usize) {
+    // Find the length by racing a hare ahead of a tortoise that jumps to
+    // the hare at every power of two.
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // With the hare `length` steps ahead, they first meet where the loop
+    // starts.
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    (start, length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 7 -> 3 ...
+    fn step(&n: &u32) -> u32 {
+        if n == 7 {
+            3
+        } else {
+            n + 1
+        }
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        let cycle = find_cycle(0, step);
+        assert_eq!((cycle.start, cycle.length), (3, 5));
+        assert_eq!(*cycle.state_at(2), 2);
+        assert_eq!(*cycle.state_at(8), 3);
+        assert_eq!(
+            *cycle.state_at(1_000_000_000),
+            (3 + (1_000_000_000 - 3) % 5) as u32
+        );
+    }
+
+    #[test]
+    fn test_state_after() {
+        assert_eq!(state_after(0, 0, step), 0);
+        assert_eq!(state_after(0, 5, step), 5);
+        assert_eq!(state_after(0, 13, step), 3);
+        assert_eq!(
+            state_after(0, 1_000_000_000, step),
+            *find_cycle(0, step).state_at(1_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_brent() {
+        assert_eq!(brent(0, step), (3, 5));
+        assert_eq!(brent(5, step), (0, 5));
+        assert_eq!(brent(7u32, |&n| n), (0, 1));
+    }
+}
@@ -0,0 +1,127 @@
+//! Decimal digits of unsigned integers, without going through strings.
+//!
+//! Everything is generic over the unsigned primitive types, so the same
+//! helpers work on the `u64` and `u128` values puzzles usually need. Zero
+//! has one digit, and numbers never have leading zeros: splitting `1000`
+//! after two digits gives `10` and `0`.
+
+use num_traits::{PrimInt, Unsigned};
+
+/// `POW10[n]` is 10 to the power `n`, for every power that fits in a `u128`.
+pub const POW10: [u128; 39] = {
+    let mut table = [1u128; 39];
+    let mut n = 1;
+    while n < table.len() {
+        table[n] = table[n - 1] * 10;
+        n += 1;
+    }
+    table
+};
+
+/// 10 to the power `n`. Panics if it does not fit in a `T`.
+pub fn pow10<T: PrimInt + Unsigned>(n: u32) -> T {
+    POW10
+        .get(n as usize)
+        .and_then(|&p| T::from(p))
+        .expect("power of ten overflows its type")
+}
+
+/// How many decimal digits `n` has.
+pub fn count<T: PrimInt + Unsigned>(n: T) -> u32 {
+    let n = n.to_u128().unwrap();
+    POW10[1..].partition_point(|&p| p <= n) as u32 + 1
+}
+
+/// `n` cut before its last `low` digits: `split(123456, 2)` is
+/// `(1234, 56)`.
+pub fn split<T: PrimInt + Unsigned>(n: T, low: u32) -> (T, T) {
+    let p = pow10::<T>(low);
+    (n / p, n % p)
+}
+
+/// The digits of `b` written after those of `a`: `concat(12, 345)` is
+/// `12345`.
+pub fn concat<T: PrimInt + Unsigned>(a: T, b: T) -> T {
+    a * pow10(count(b)) + b
+}
+
+/// The digits of `block` written `times` times over.
+pub fn repeat<T: PrimInt + Unsigned>(block: T, times: u32) -> T {
+    let shift = pow10::<T>(count(block));
+    (0..times).fold(T::zero(), |n, _| n * shift + block)
+}
+
+/// The block whose digits, written `times` times, make up `n`, if there is
+/// one: `repeated_block(123123, 2)` is `Some(123)`.
+pub fn repeated_block<T: PrimInt + Unsigned>(n: T, times: u32) -> Option<T> {
+    let digits = count(n);
+    if times == 0 || !digits.is_multiple_of(times) {
+        return None;
+    }
+    let (_, block) = split(n, digits / times);
+    (count(block) == digits / times && repeat(block, times) == n).then_some(block)
+}
+
+/// Whether `n` is some block of digits written at least twice.
+pub fn is_repetition<T: PrimInt + Unsigned>(n: T) -> bool {
+    (2..=count(n)).any(|times| repeated_block(n, times).is_some())
+}
+
+/// The digits of `n`, most significant first.
+pub fn digits<T: PrimInt + Unsigned>(
+    n: T,
+) -> impl DoubleEndedIterator<Item = u8> + ExactSizeIterator {
+    let ten = T::from(10).unwrap();
+    (0..count(n))
+        .rev()
+        .map(move |k| (n / pow10(k) % ten).to_u8().unwrap())
+}
+
+/// The number with the given digits, most significant first.
+pub fn from_digits<T: PrimInt + Unsigned>(digits: impl IntoIterator<Item = u8>) -> T {
+    let ten = T::from(10).unwrap();
+    digits
+        .into_iter()
+        .fold(T::zero(), |n, d| n * ten + T::from(d).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_and_split() {
+        assert_eq!(count(0u64), 1);
+        assert_eq!(count(9u64), 1);
+        assert_eq!(count(10u64), 2);
+        assert_eq!(count(u64::MAX), 20);
+        assert_eq!(count(u128::MAX), 39);
+        assert_eq!(pow10::<u64>(19), 10_000_000_000_000_000_000);
+
+        assert_eq!(split(123456u64, 2), (1234, 56));
+        assert_eq!(split(1000u64, 2), (10, 0));
+        assert_eq!(concat(12u64, 345), 12345);
+        assert_eq!(concat(6u64, 0), 60);
+    }
+
+    #[test]
+    fn test_repetition() {
+        assert_eq!(repeat(12u64, 3), 121212);
+        assert_eq!(repeated_block(123123u64, 2), Some(123));
+        assert_eq!(repeated_block(1010u64, 2), Some(10));
+        assert_eq!(repeated_block(111u64, 3), Some(1));
+        assert_eq!(repeated_block(111u64, 2), None);
+        assert_eq!(repeated_block(1001u64, 2), None);
+        assert!(is_repetition(824824824u64));
+        assert!(!is_repetition(1698522u64));
+        assert!(!is_repetition(7u64));
+    }
+
+    #[test]
+    fn test_digits() {
+        assert_eq!(digits(9041u64).collect::<Vec<_>>(), vec![9, 0, 4, 1]);
+        assert_eq!(digits(9041u64).rev().collect::<Vec<_>>(), vec![1, 4, 0, 9]);
+        assert_eq!(digits(0u128).len(), 1);
+        assert_eq!(from_digits::<u128>(digits(u128::MAX)), u128::MAX);
+    }
+}
@@ -0,0 +1,175 @@
+use std::fmt;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    Parse(ParseError),
+    /// No input file was found for a day; `tried` lists where it was looked for.
+    MissingInput {
+        year: u16,
+        day: u8,
+        tried: Vec<PathBuf>,
+    },
+    /// A solution panicked instead of returning; holds the panic message.
+    Panic(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "io error: {}", e),
+            Error::Parse(e) => write!(f, "parse error {}", e),
+            Error::MissingInput { year, day, tried } => {
+                write!(f, "no input for {} day {}, looked for", year, day)?;
+                for (i, path) in tried.iter().enumerate() {
+                    let sep = if i == 0 { " " } else { ", " };
+                    write!(f, "{}{}", sep, path.display())?;
+                }
+                Ok(())
+            }
+            Error::Panic(message) => write!(f, "panicked: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Parse(_) | Error::MissingInput { .. } | Error::Panic(_) => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+/// Where an input stopped making sense: the offending text, its 1-based
+/// line and column, and a short description of what should have been there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+    /// The whole input line `text` was found on, kept for diagnostics.
+    pub source_line: String,
+}
+
+impl ParseError {
+    /// Builds an error for `text`, which should be a slice borrowed from
+    /// `input` (as handed out by `lines()`, `split()` and friends) so its
+    /// position can be worked out. Text that is not part of `input` is
+    /// searched for, and failing that is reported at the end of the input.
+    pub fn at(input: &str, text: &str, expected: impl Into<String>) -> Self {
+        let start = input.as_ptr() as usize;
+        let offset = (text.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|offset| offset + text.len() <= input.len())
+            .or_else(|| input.find(text).filter(|_| !text.is_empty()))
+            .unwrap_or(input.len());
+
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        Self {
+            line: input[..offset].matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            text: text.to_string(),
+            expected: expected.into(),
+            source_line: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+        }
+    }
+
+    /// The error followed by the offending line with the text underlined:
+    ///
+    /// ```text
+    /// parse error at line 2, column 4: expected a number, found `x`
+    ///     2 | 50 x 2
+    ///       |    ^
+    /// ```
+    pub fn diagnostic(&self) -> String {
+        let gutter = self.line.to_string();
+        let width = self.text.chars().count().max(1);
+        format!(
+            "parse error {}\n    {} | {}\n    {} | {}{}",
+            self,
+            gutter,
+            self.source_line,
+            " ".repeat(gutter.len()),
+            " ".repeat(self.column - 1),
+            "^".repeat(width)
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "at line {}, column {}: expected {}, found ",
+            self.line, self.column, self.expected
+        )?;
+        if self.text.is_empty() {
+            write!(f, "nothing")
+        } else {
+            write!(f, "`{}`", self.text)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "seeds: 1 2\n\n50 x 2\n";
+
+    #[test]
+    fn test_at_borrowed_text() {
+        let line = INPUT.lines().nth(2).unwrap();
+        let text = line.split(' ').nth(1).unwrap();
+        let e = ParseError::at(INPUT, text, "a number");
+
+        assert_eq!((e.line, e.column), (3, 4));
+        assert_eq!(e.text, "x");
+        assert_eq!(e.source_line, "50 x 2");
+        assert_eq!(
+            e.to_string(),
+            "at line 3, column 4: expected a number, found `x`"
+        );
+    }
+
+    #[test]
+    fn test_at_missing_text() {
+        let e = ParseError::at(INPUT, "", "a map header");
+        assert_eq!((e.line, e.column), (4, 1));
+        assert!(e.to_string().ends_with("found nothing"));
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let e = ParseError::at(INPUT, &INPUT[7..8], "a number");
+        assert_eq!(
+            e.diagnostic(),
+            "parse error at line 1, column 8: expected a number, found `1`\n    1 | seeds: 1 2\n      |        ^"
+        );
+    }
+}
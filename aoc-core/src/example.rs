@@ -0,0 +1,178 @@
+//! Example inputs kept as files next to a year crate's sources:
+//!
+//! ```text
+//! examples/day05/1.txt    the input
+//! examples/day05/1.toml   expected answers, e.g. `part1 = 35` and `part2 = 46`
+//! ```
+//!
+//! The sidecar may leave out a part whose answer is unknown or too slow to
+//! check; a fixture without a sidecar can still be run with `--example`.
+//! The year's build script generates a test for every fixture that has one.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::{Error, Input, ParseError, Puzzle, Result};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub path: PathBuf,
+    part1: Option<String>,
+    part2: Option<String>,
+}
+
+impl Example {
+    /// Looks up fixture `name` of `day` in the year crate at `crate_dir`.
+    pub fn find(year: u16, day: u8, crate_dir: &str, name: &str) -> Result<Example> {
+        let tried = dirs(crate_dir, day)
+            .into_iter()
+            .map(|dir| dir.join(format!("{}.txt", name)))
+            .collect::<Vec<_>>();
+        match tried.iter().find(|path| path.is_file()) {
+            Some(path) => Example::load(path),
+            None => Err(Error::MissingInput { year, day, tried }),
+        }
+    }
+
+    /// Every fixture of `day`, sorted by name. A day without an examples
+    /// directory has none.
+    pub fn all(day: u8, crate_dir: &str) -> Result<Vec<Example>> {
+        let mut examples = Vec::new();
+        for dir in dirs(crate_dir, day) {
+            let entries = match fs::read_dir(&dir) {
+                Ok(entries) => entries,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e.into()),
+            };
+            for entry in entries {
+                let path = entry?.path();
+                if path.extension().is_some_and(|ext| ext == "txt") {
+                    examples.push(Example::load(&path)?);
+                }
+            }
+        }
+        examples.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(examples)
+    }
+
+    fn load(path: &Path) -> Result<Example> {
+        let name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default()
+            .to_string();
+        let sidecar = path.with_extension("toml");
+        let (part1, part2) = match fs::read_to_string(&sidecar) {
+            Ok(content) => parse_sidecar(&content)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => (None, None),
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(Example {
+            name,
+            path: path.to_path_buf(),
+            part1,
+            part2,
+        })
+    }
+
+    pub fn input(&self) -> Input {
+        Input::File(self.path.clone())
+    }
+
+    pub fn expected(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn has_expected(&self) -> bool {
+        self.part1.is_some() || self.part2.is_some()
+    }
+}
+
+/// `examples/dayNN/` and the unpadded `examples/dayN/` the older years use.
+fn dirs(crate_dir: &str, day: u8) -> Vec<PathBuf> {
+    let root = Path::new(crate_dir).join("examples");
+    let mut dirs = vec![root.join(format!("day{:02}", day))];
+    if day < 10 {
+        dirs.push(root.join(format!("day{}", day)));
+    }
+    dirs
+}
+
+fn parse_sidecar(content: &str) -> Result<(Option<String>, Option<String>)> {
+    let table: toml::Table = content.parse().map_err(|e: toml::de::Error| {
+        let text = e.span().map_or("", |span| &content[span]);
+        ParseError::at(content, text, e.message().to_string())
+    })?;
+
+    let mut parts = (None, None);
+    for (key, value) in &table {
+        let slot = match key.as_str() {
+            "part1" => &mut parts.0,
+            "part2" => &mut parts.1,
+            _ => return Err(ParseError::at(content, key, "`part1` or `part2`").into()),
+        };
+        *slot = match value {
+            toml::Value::Integer(n) => Some(n.to_string()),
+            toml::Value::String(s) => Some(s.clone()),
+            _ => return Err(ParseError::at(content, key, "an integer or a string answer").into()),
+        };
+    }
+
+    Ok(parts)
+}
+
+/// Solves fixture `name` of `puzzle` and asserts every answer its sidecar
+/// records. This is what the generated example tests call.
+pub fn check(puzzle: &Puzzle, name: &str) {
+    let example = Example::find(puzzle.year, puzzle.day, puzzle.crate_dir, name)
+        .unwrap_or_else(|e| panic!("example {}: {}", name, e));
+    let input = example
+        .input()
+        .read()
+        .unwrap_or_else(|e| panic!("{}: {}", example.path.display(), e));
+    let parsed = puzzle
+        .parse(&input)
+        .unwrap_or_else(|e| panic!("{}: {}", example.path.display(), e));
+
+    for part in 1..=2 {
+        if let Some(expected) = example.expected(part) {
+            let actual = puzzle.solve(parsed.as_ref(), part).to_string();
+            assert_eq!(
+                actual, expected,
+                "{} day {} example {} part {}",
+                puzzle.year, puzzle.day, name, part
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sidecar() {
+        let parts = parse_sidecar("part1 = 35\npart2 = \"abc\"\n").unwrap();
+        assert_eq!(parts, (Some("35".to_string()), Some("abc".to_string())));
+
+        let parts = parse_sidecar("part2 = 46\n").unwrap();
+        assert_eq!(parts, (None, Some("46".to_string())));
+    }
+
+    #[test]
+    fn test_parse_sidecar_errors() {
+        match parse_sidecar("part1 = 35\npart3 = 1\n") {
+            Err(Error::Parse(e)) => assert_eq!((e.line, e.text.as_str()), (2, "part3")),
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert!(parse_sidecar("part1 = [1]").is_err());
+        assert!(parse_sidecar("part1 = ").is_err());
+    }
+}
@@ -0,0 +1,279 @@
+//! Points and compass directions on the plane.
+//!
+//! Coordinates follow [`Grid`](crate::Grid): rows grow downwards and columns
+//! grow to the right, so [`Direction::Up`] decreases the row.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::grid::Pos;
+
+/// A signed `(row, col)` position or offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point {
+    pub row: i64,
+    pub col: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(row: i64, col: i64) -> Self {
+        Point { row, col }
+    }
+
+    /// Sum of the absolute row and column differences.
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.row - other.row).abs() + (self.col - other.col).abs()
+    }
+
+    /// The larger of the absolute row and column differences, which is the
+    /// number of king moves between the two points.
+    pub fn chebyshev(self, other: Point) -> i64 {
+        (self.row - other.row)
+            .abs()
+            .max((self.col - other.col).abs())
+    }
+
+    /// The neighbouring point one step towards `dir`.
+    pub fn step(self, dir: impl Into<Direction8>) -> Point {
+        self + dir.into().delta()
+    }
+
+    /// The four orthogonal neighbours, in [`Direction::ALL`] order.
+    pub fn neighbours4(self) -> [Point; 4] {
+        Direction::ALL.map(|dir| self.step(dir))
+    }
+
+    /// All eight neighbours, in [`Direction8::ALL`] order.
+    pub fn neighbours8(self) -> [Point; 8] {
+        Direction8::ALL.map(|dir| self.step(dir))
+    }
+
+    /// The grid position of this point, or `None` if either coordinate is
+    /// negative.
+    pub fn to_pos(self) -> Option<Pos> {
+        Some((self.row.try_into().ok()?, self.col.try_into().ok()?))
+    }
+}
+
+impl From<Pos> for Point {
+    fn from((row, col): Pos) -> Self {
+        Point::new(row as i64, col as i64)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.row, -self.col)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i64) -> Point {
+        Point::new(self.row * factor, self.col * factor)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+/// One of the four orthogonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Reads `^>v<` arrows and `UDLR` letters.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '^' | 'U' => Some(Direction::Up),
+            '>' | 'R' => Some(Direction::Right),
+            'v' | 'D' => Some(Direction::Down),
+            '<' | 'L' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// The arrow for this direction, as used in puzzle maps.
+    pub fn to_char(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn delta(self) -> Point {
+        Direction8::from(self).delta()
+    }
+
+    /// Whether this direction moves along a row rather than a column.
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+}
+
+/// One of the four orthogonal or four diagonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Clockwise from up.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// Turns an eighth of a full turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns an eighth of a full turn counter-clockwise.
+    pub fn turn_left(self) -> Self {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn delta(self) -> Point {
+        match self {
+            Direction8::Up => Point::new(-1, 0),
+            Direction8::UpRight => Point::new(-1, 1),
+            Direction8::Right => Point::new(0, 1),
+            Direction8::DownRight => Point::new(1, 1),
+            Direction8::Down => Point::new(1, 0),
+            Direction8::DownLeft => Point::new(1, -1),
+            Direction8::Left => Point::new(0, -1),
+            Direction8::UpLeft => Point::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Self {
+        Direction8::ALL[dir as usize * 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_math() {
+        let a = Point::new(1, -2);
+        let b = Point::new(-3, 4);
+        assert_eq!(a + b, Point::new(-2, 2));
+        assert_eq!(a - b, Point::new(4, -6));
+        assert_eq!(-a * 3, Point::new(-3, 6));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!(Point::new(2, 3).to_pos(), Some((2, 3)));
+        assert_eq!(a.to_pos(), None);
+    }
+
+    #[test]
+    fn test_turning() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+        assert_eq!(Direction8::UpLeft.turn_right(), Direction8::Up);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownRight.reverse(), Direction8::UpLeft);
+        for dir in Direction::ALL {
+            assert_eq!(dir.delta() + dir.reverse().delta(), Point::ORIGIN);
+            assert_eq!(dir.turn_right().turn_left(), dir);
+        }
+    }
+
+    #[test]
+    fn test_from_char() {
+        assert_eq!(Direction::from_char('^'), Some(Direction::Up));
+        assert_eq!(Direction::from_char('D'), Some(Direction::Down));
+        assert_eq!(Direction::from_char('x'), None);
+        assert_eq!(
+            Direction::from_char(Direction::Left.to_char()),
+            Some(Direction::Left)
+        );
+        assert_eq!(Point::new(5, 5).step(Direction::Left), Point::new(5, 4));
+    }
+}
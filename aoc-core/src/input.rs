@@ -0,0 +1,181 @@
+use std::ffi::OsString;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use crate::{Error, Result};
+
+/// Environment variable naming a directory laid out as `<year>/dayNN.txt`,
+/// checked before a year crate's own `inputs/`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Where a puzzle's input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Input {
+    /// Picks the input for a day, in order of preference:
+    ///
+    /// 1. `explicit`, where `-` means stdin,
+    /// 2. `$AOC_INPUT_DIR/<year>/`,
+    /// 3. `inputs/` in the year crate at `crate_dir`.
+    ///
+    /// Both `dayNN.txt` and `dayN.txt` are accepted in either directory. An
+    /// explicit path is taken as is; otherwise a day with no file in any of
+    /// the directories is an [`Error::MissingInput`].
+    pub fn resolve(year: u16, day: u8, crate_dir: &str, explicit: Option<&Path>) -> Result<Input> {
+        resolve(
+            year,
+            day,
+            crate_dir,
+            explicit,
+            std::env::var_os(INPUT_DIR_VAR),
+        )
+    }
+
+    /// Reads the whole input. One trailing newline is dropped, so a file
+    /// saved by an editor or piped through `echo` reads the same as the
+    /// inputs the solutions were written against.
+    pub fn read(&self) -> Result<String> {
+        let mut text = match self {
+            Input::Stdin => {
+                let mut buffer = String::new();
+                io::stdin().read_to_string(&mut buffer)?;
+                buffer
+            }
+            Input::File(path) => fs::read_to_string(path)?,
+        };
+
+        if text.ends_with('\n') {
+            text.pop();
+            if text.ends_with('\r') {
+                text.pop();
+            }
+        }
+        Ok(text)
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Input::Stdin => write!(f, "<stdin>"),
+            Input::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+fn resolve(
+    year: u16,
+    day: u8,
+    crate_dir: &str,
+    explicit: Option<&Path>,
+    env_dir: Option<OsString>,
+) -> Result<Input> {
+    if let Some(path) = explicit {
+        return Ok(if path == Path::new("-") {
+            Input::Stdin
+        } else {
+            Input::File(path.to_path_buf())
+        });
+    }
+
+    let mut dirs = Vec::new();
+    if let Some(dir) = env_dir.filter(|dir| !dir.is_empty()) {
+        dirs.push(PathBuf::from(dir).join(year.to_string()));
+    }
+    dirs.push(Path::new(crate_dir).join("inputs"));
+
+    let tried = dirs
+        .iter()
+        .flat_map(|dir| candidates(dir, day))
+        .collect::<Vec<_>>();
+    match tried.iter().find(|path| path.is_file()) {
+        Some(path) => Ok(Input::File(path.clone())),
+        None => Err(Error::MissingInput { year, day, tried }),
+    }
+}
+
+fn candidates(dir: &Path, day: u8) -> Vec<PathBuf> {
+    let mut names = vec![format!("day{:02}.txt", day)];
+    if day < 10 {
+        names.push(format!("day{}.txt", day));
+    }
+    names.into_iter().map(|name| dir.join(name)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("aoc-core-input-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_explicit_input() {
+        let input = resolve(2023, 5, "/nowhere", Some(Path::new("my.txt")), None).unwrap();
+        assert_eq!(input, Input::File(PathBuf::from("my.txt")));
+
+        let input = resolve(2023, 5, "/nowhere", Some(Path::new("-")), None).unwrap();
+        assert_eq!(input, Input::Stdin);
+    }
+
+    #[test]
+    fn test_padded_and_unpadded_names() {
+        let dir = scratch_dir("names");
+        fs::create_dir_all(dir.join("inputs")).unwrap();
+        fs::write(dir.join("inputs/day3.txt"), "3").unwrap();
+        fs::write(dir.join("inputs/day04.txt"), "4").unwrap();
+        let crate_dir = dir.to_str().unwrap();
+
+        let input = resolve(2021, 3, crate_dir, None, None).unwrap();
+        assert_eq!(input, Input::File(dir.join("inputs/day3.txt")));
+        let input = resolve(2021, 4, crate_dir, None, None).unwrap();
+        assert_eq!(input, Input::File(dir.join("inputs/day04.txt")));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_env_dir_comes_first() {
+        let dir = scratch_dir("env");
+        fs::create_dir_all(dir.join("inputs")).unwrap();
+        fs::create_dir_all(dir.join("shared/2024")).unwrap();
+        fs::write(dir.join("inputs/day01.txt"), "local").unwrap();
+        fs::write(dir.join("shared/2024/day01.txt"), "shared\n").unwrap();
+        let crate_dir = dir.to_str().unwrap();
+
+        let env_dir = Some(dir.join("shared").into_os_string());
+        let input = resolve(2024, 1, crate_dir, None, env_dir).unwrap();
+        assert_eq!(input.read().unwrap(), "shared");
+
+        // a day missing from the shared directory falls back to inputs/
+        fs::remove_file(dir.join("shared/2024/day01.txt")).unwrap();
+        let env_dir = Some(dir.join("shared").into_os_string());
+        let input = resolve(2024, 1, crate_dir, None, env_dir).unwrap();
+        assert_eq!(input.read().unwrap(), "local");
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_missing_input() {
+        let e = resolve(2022, 12, "/nowhere", None, None).unwrap_err();
+        match e {
+            Error::MissingInput { year, day, tried } => {
+                assert_eq!((year, day), (2022, 12));
+                assert_eq!(tried, vec![PathBuf::from("/nowhere/inputs/day12.txt")]);
+            }
+            e => panic!("unexpected error: {}", e),
+        }
+    }
+}
@@ -0,0 +1,312 @@
+//! Integer intervals and sets of them.
+//!
+//! Intervals are half-open, `start..end`, like Rust ranges; puzzles that give
+//! inclusive bounds such as `3-5` go through [`Interval::inclusive`].
+
+use std::fmt;
+use std::ops::{Range, RangeInclusive};
+
+use num_traits::PrimInt;
+
+/// The integers from `start` up to but not including `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: PrimInt> Interval<T> {
+    pub fn new(start: T, end: T) -> Self {
+        Interval { start, end }
+    }
+
+    /// The integers from `first` to `last`, both included. Panics if `last`
+    /// is `T::MAX`, as the end of the interval would not fit in a `T`.
+    pub fn inclusive(first: T, last: T) -> Self {
+        let end = last
+            .checked_add(&T::one())
+            .expect("interval end overflows its type");
+        Interval::new(first, end)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// How many integers the interval holds.
+    pub fn len(&self) -> T {
+        if self.is_empty() {
+            T::zero()
+        } else {
+            self.end - self.start
+        }
+    }
+
+    /// The largest value in the interval, which must not be empty.
+    pub fn last(&self) -> T {
+        self.end - T::one()
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    /// Whether every value of `other` is also in this interval.
+    pub fn contains_interval(&self, other: &Interval<T>) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// The values in both intervals, or `None` if there are none.
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        let overlap = Interval::new(self.start.max(other.start), self.end.min(other.end));
+        (!overlap.is_empty()).then_some(overlap)
+    }
+
+    /// The same interval moved by `offset`.
+    pub fn shift(&self, offset: T) -> Interval<T> {
+        Interval::new(self.start + offset, self.end + offset)
+    }
+}
+
+impl<T: PrimInt> From<Range<T>> for Interval<T> {
+    fn from(range: Range<T>) -> Self {
+        Interval::new(range.start, range.end)
+    }
+}
+
+/// Panics on a range ending at `T::MAX`, like [`Interval::inclusive`].
+impl<T: PrimInt> From<RangeInclusive<T>> for Interval<T> {
+    fn from(range: RangeInclusive<T>) -> Self {
+        Interval::inclusive(*range.start(), *range.end())
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Interval<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+/// A set of integers stored as sorted, disjoint, non-adjacent intervals.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every value of `interval`, merging it with any interval it
+    /// overlaps or touches.
+    pub fn insert(&mut self, interval: impl Into<Interval<T>>) {
+        let mut interval = interval.into();
+        if interval.is_empty() {
+            return;
+        }
+
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+        if first < last {
+            interval.start = interval.start.min(self.intervals[first].start);
+            interval.end = interval.end.max(self.intervals[last - 1].end);
+        }
+        self.intervals.splice(first..last, [interval]);
+    }
+
+    /// Whether `value` is in the set, in O(log n).
+    pub fn contains(&self, value: T) -> bool {
+        self.containing(value).is_some()
+    }
+
+    /// The interval of the set holding `value`, in O(log n).
+    pub fn containing(&self, value: T) -> Option<&Interval<T>> {
+        let i = self.intervals.partition_point(|i| i.end <= value);
+        self.intervals.get(i).filter(|i| i.contains(value))
+    }
+
+    /// Whether every value of `interval` is in the set, in O(log n).
+    pub fn contains_interval(&self, interval: &Interval<T>) -> bool {
+        interval.is_empty()
+            || self
+                .containing(interval.start)
+                .is_some_and(|i| i.contains_interval(interval))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// How many integers the set holds.
+    pub fn len(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::zero(), |total, interval| total + interval.len())
+    }
+
+    /// The intervals in increasing order.
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> + '_ {
+        self.intervals.iter()
+    }
+
+    /// The intervals between consecutive intervals of the set.
+    pub fn gaps(&self) -> impl Iterator<Item = Interval<T>> + '_ {
+        self.intervals
+            .windows(2)
+            .map(|pair| Interval::new(pair[0].end, pair[1].start))
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut union = self.clone();
+        for &interval in &other.intervals {
+            union.insert(interval);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = Vec::new();
+        let (mut a, mut b) = (0, 0);
+        while a < self.intervals.len() && b < other.intervals.len() {
+            let (x, y) = (self.intervals[a], other.intervals[b]);
+            intervals.extend(x.intersection(&y));
+            if x.end < y.end {
+                a += 1;
+            } else {
+                b += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /// The values of this set that are not in `other`.
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intervals = Vec::new();
+        let mut b = 0;
+        for &interval in &self.intervals {
+            let mut rest = interval;
+            while b < other.intervals.len() && other.intervals[b].end <= rest.start {
+                b += 1;
+            }
+            let mut cut = b;
+            while cut < other.intervals.len() && other.intervals[cut].start < rest.end {
+                let hole = other.intervals[cut];
+                if rest.start < hole.start {
+                    intervals.push(Interval::new(rest.start, hole.start));
+                }
+                rest.start = rest.start.max(hole.end);
+                cut += 1;
+            }
+            if !rest.is_empty() {
+                intervals.push(rest);
+            }
+        }
+        IntervalSet { intervals }
+    }
+}
+
+impl<T: PrimInt, I: Into<Interval<T>>> FromIterator<I> for IntervalSet<T> {
+    fn from_iter<It: IntoIterator<Item = I>>(iter: It) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in iter {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+impl<T: PrimInt, I: Into<Interval<T>>> Extend<I> for IntervalSet<T> {
+    fn extend<It: IntoIterator<Item = I>>(&mut self, iter: It) {
+        for interval in iter {
+            self.insert(interval);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<i64>]) -> IntervalSet<i64> {
+        ranges.iter().cloned().collect()
+    }
+
+    fn ranges(set: &IntervalSet<i64>) -> Vec<Range<i64>> {
+        set.iter().map(|i| i.start..i.end).collect()
+    }
+
+    #[test]
+    fn test_interval() {
+        let a = Interval::inclusive(3, 5);
+        assert_eq!(a, Interval::new(3, 6));
+        assert_eq!((a.len(), a.last()), (3, 5));
+        assert!(a.contains(5) && !a.contains(6));
+        assert_eq!(
+            a.intersection(&Interval::new(5, 9)),
+            Some(Interval::new(5, 6))
+        );
+        assert!(!a.overlaps(&Interval::new(6, 9)));
+        assert!(a.contains_interval(&Interval::new(4, 6)));
+        assert_eq!(Interval::new(4, 2).len(), 0);
+    }
+
+    #[test]
+    fn test_inclusive_up_to_max() {
+        let a = Interval::inclusive(250u8, 254);
+        assert_eq!((a.len(), a.last()), (5, 254));
+    }
+
+    #[test]
+    #[should_panic(expected = "interval end overflows its type")]
+    fn test_inclusive_past_max() {
+        Interval::inclusive(250u8, u8::MAX);
+    }
+
+    #[test]
+    fn test_insert_merges() {
+        let set = set(&[10..14, 3..6, 16..20, 5..8, 14..15]);
+        assert_eq!(ranges(&set), vec![3..8, 10..15, 16..20]);
+        assert_eq!(set.len(), 14);
+        assert_eq!(
+            set.gaps().collect::<Vec<_>>(),
+            vec![Interval::new(8, 10), Interval::new(15, 16),]
+        );
+
+        let mut set = set;
+        set.insert(0..100);
+        assert_eq!(ranges(&set), vec![0..100]);
+    }
+
+    #[test]
+    fn test_queries() {
+        let set = set(&[3..6, 10..15]);
+        assert!(set.contains(3) && set.contains(14));
+        assert!(!set.contains(6) && !set.contains(2) && !set.contains(15));
+        assert!(set.contains_interval(&Interval::new(11, 15)));
+        assert!(!set.contains_interval(&Interval::new(5, 11)));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = set(&[0..10, 20..30]);
+        let b = set(&[5..25, 28..40]);
+        assert_eq!(ranges(&a.union(&b)), vec![0..40]);
+        assert_eq!(ranges(&a.intersection(&b)), vec![5..10, 20..25, 28..30]);
+        assert_eq!(ranges(&a.difference(&b)), vec![0..5, 25..28]);
+        assert_eq!(ranges(&b.difference(&a)), vec![10..20, 30..40]);
+        assert!(a.difference(&a).is_empty());
+    }
+}
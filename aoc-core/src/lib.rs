@@ -0,0 +1,38 @@
+mod answer;
+pub mod build;
+pub mod cycle;
+pub mod digits;
+mod error;
+pub mod example;
+pub mod geometry;
+pub mod grid;
+mod input;
+pub mod interval;
+pub mod math;
+pub mod memo;
+pub mod parse;
+pub mod piecewise;
+mod puzzle;
+pub mod search;
+mod solution;
+
+pub use answer::Answer;
+pub use error::{Error, ParseError, Result};
+pub use geometry::{Direction, Direction8, Point};
+pub use grid::Grid;
+pub use input::{Input, INPUT_DIR_VAR};
+pub use interval::{Interval, IntervalSet};
+pub use memo::Memo;
+pub use parse::Span;
+pub use piecewise::PiecewiseMap;
+pub use puzzle::Puzzle;
+pub use solution::{solve, Solution};
+
+pub fn is_same_vec(vec1: &[i32], vec2: &[i32]) -> bool {
+    let matching = vec1
+        .iter()
+        .zip(vec2.iter())
+        .filter(|&(a, b)| a == b)
+        .count();
+    matching == vec1.len() && matching == vec2.len()
+}
@@ -0,0 +1,276 @@
+//! Number theory: divisors, congruences and integers modulo a constant.
+//!
+//! Puzzles about things that repeat on different periods come down to
+//! [`lcm`] when every period starts at zero, and to [`crt`] when they are
+//! offset. [`Mod`] does arithmetic on a dial or ring of fixed size without
+//! sprinkling `%` everywhere.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use num_traits::{PrimInt, Signed};
+
+fn abs<T: PrimInt>(value: T) -> T {
+    if value < T::zero() {
+        T::zero() - value
+    } else {
+        value
+    }
+}
+
+/// The greatest common divisor, which is never negative. `gcd(0, 0)` is 0.
+pub fn gcd<T: PrimInt>(a: T, b: T) -> T {
+    let (mut a, mut b) = (abs(a), abs(b));
+    while b != T::zero() {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, which is never negative. It is 0 if either
+/// argument is.
+pub fn lcm<T: PrimInt>(a: T, b: T) -> T {
+    if a == T::zero() || b == T::zero() {
+        return T::zero();
+    }
+    abs(a / gcd(a, b) * b)
+}
+
+/// The greatest common divisor of all the values, or 0 if there are none.
+pub fn gcd_all<T: PrimInt>(values: &[T]) -> T {
+    values.iter().fold(T::zero(), |acc, &v| gcd(acc, v))
+}
+
+/// The least common multiple of all the values, or 1 if there are none.
+pub fn lcm_all<T: PrimInt>(values: &[T]) -> T {
+    values.iter().fold(T::one(), |acc, &v| lcm(acc, v))
+}
+
+/// Extended Euclid: `(g, x, y)` with `g = gcd(a, b)` and `a * x + b * y = g`.
+pub fn extended_gcd<T: PrimInt + Signed>(a: T, b: T) -> (T, T, T) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+    while r != T::zero() {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+    if old_r < T::zero() {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The `x` in `0..modulus` with `value * x ≡ 1`, if `value` and `modulus`
+/// are coprime.
+pub fn mod_inverse(value: i64, modulus: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd(value as i128, modulus as i128);
+    (g == 1).then(|| x.rem_euclid(modulus as i128) as i64)
+}
+
+/// `a * b % modulus` without overflowing.
+pub fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    (a as u128 * b as u128 % modulus as u128) as u64
+}
+
+/// `base` to the power `exp`, modulo `modulus`, by repeated squaring.
+pub fn pow_mod(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    let mut base = base % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Chinese Remainder Theorem: the `(x, m)` with `0 <= x < m` such that `n`
+/// satisfies every `n ≡ residue (mod modulus)` exactly when `n ≡ x (mod m)`.
+///
+/// The moduli need not be coprime; `m` is their least common multiple.
+/// Returns `None` if the congruences contradict each other or a modulus is
+/// zero, and panics if `m` does not fit in an `i64`.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    let (mut x, mut m) = (0i128, 1i128);
+    for &(residue, modulus) in congruences {
+        if modulus == 0 {
+            return None;
+        }
+        let (residue, modulus) = (residue as i128, abs(modulus as i128));
+        // x + m * k ≡ residue (mod modulus) has a solution for k only if
+        // the gcd divides the difference.
+        let (g, inverse, _) = extended_gcd(m, modulus);
+        let diff = residue - x;
+        if diff % g != 0 {
+            return None;
+        }
+        let step = modulus / g;
+        let k = (diff / g % step * inverse % step).rem_euclid(step);
+        x += m * k;
+        m *= step;
+        assert!(m <= i64::MAX as i128, "CRT modulus overflows i64");
+        x = x.rem_euclid(m);
+    }
+    Some((x as i64, m as i64))
+}
+
+/// An integer modulo `M`, always kept in `0..M`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Mod<const M: u64>(u64);
+
+impl<const M: u64> Mod<M> {
+    pub const fn new(value: u64) -> Self {
+        Mod(value % M)
+    }
+
+    /// The representative in `0..M`.
+    pub const fn value(self) -> u64 {
+        self.0
+    }
+
+    pub fn pow(self, exp: u64) -> Self {
+        Mod(pow_mod(self.0, exp, M))
+    }
+
+    /// The value that multiplies with this one to 1, if there is one.
+    pub fn inverse(self) -> Option<Self> {
+        // `M` may not fit in an `i64`, so this cannot go through `mod_inverse`.
+        let (g, x, _) = extended_gcd(self.0 as i128, M as i128);
+        (g == 1).then(|| Mod(x.rem_euclid(M as i128) as u64))
+    }
+}
+
+impl<const M: u64> From<u64> for Mod<M> {
+    fn from(value: u64) -> Self {
+        Mod::new(value)
+    }
+}
+
+impl<const M: u64> From<i64> for Mod<M> {
+    fn from(value: i64) -> Self {
+        Mod((value as i128).rem_euclid(M as i128) as u64)
+    }
+}
+
+impl<const M: u64> Add for Mod<M> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Mod(((self.0 as u128 + other.0 as u128) % M as u128) as u64)
+    }
+}
+
+impl<const M: u64> AddAssign for Mod<M> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<const M: u64> Sub for Mod<M> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl<const M: u64> SubAssign for Mod<M> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<const M: u64> Neg for Mod<M> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Mod((M - self.0) % M)
+    }
+}
+
+impl<const M: u64> Mul for Mod<M> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Mod(mul_mod(self.0, other.0, M))
+    }
+}
+
+impl<const M: u64> MulAssign for Mod<M> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<const M: u64> fmt::Display for Mod<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gcd_lcm() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0, 5), 5);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(-4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+        assert_eq!(gcd_all(&[12u64, 18, 27]), 3);
+        assert_eq!(lcm_all(&[2u64, 3, 4, 5]), 60);
+        assert_eq!(lcm_all::<u64>(&[]), 1);
+
+        let (g, x, y) = extended_gcd(240i64, 46);
+        assert_eq!(g, 2);
+        assert_eq!(240 * x + 46 * y, 2);
+        assert_eq!(mod_inverse(3, 7), Some(5));
+        assert_eq!(mod_inverse(4, 8), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        // Moduli sharing a factor.
+        assert_eq!(crt(&[(3, 4), (5, 6)]), Some((11, 12)));
+        assert_eq!(crt(&[(1, 4), (2, 6)]), None);
+        assert_eq!(crt(&[(-1, 10)]), Some((9, 10)));
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(2, 3), (0, 0)]), None);
+    }
+
+    #[test]
+    fn test_mod() {
+        type Dial = Mod<100>;
+        let mut dial = Dial::new(50);
+        dial -= Dial::from(68i64);
+        assert_eq!(dial.value(), 82);
+        dial += Dial::new(1018);
+        assert_eq!(dial, Dial::new(0));
+        assert_eq!(-Dial::new(1), Dial::new(99));
+
+        const P: u64 = 1_000_000_007;
+        let big = Mod::<P>::new(P - 1);
+        assert_eq!(big * big, Mod::new(1));
+        assert_eq!(Mod::<P>::new(2).pow(P - 1), Mod::new(1));
+        let three = Mod::<P>::new(3);
+        assert_eq!(three * three.inverse().unwrap(), Mod::new(1));
+        assert_eq!(Mod::<10>::new(4).inverse(), None);
+
+        // The largest prime below 2^64, well past `i64::MAX`.
+        type Big = Mod<18_446_744_073_709_551_557>;
+        for value in [2, 3, i64::MAX as u64, u64::MAX - 59] {
+            let value = Big::new(value);
+            assert_eq!(value * value.inverse().unwrap(), Big::new(1));
+        }
+    }
+}
@@ -0,0 +1,145 @@
+//! Caching the results of pure recursive functions.
+//!
+//! A [`Memo`] holds the cache; [`Memo::get`] runs a closure that receives a
+//! [`Recurse`] handle, and every recursive call made through the handle is
+//! looked up before it is computed:
+//!
+//! ```
+//! use aoc_core::Memo;
+//!
+//! let mut memo = Memo::new();
+//! let fib = memo.get(90, |rec, &n| {
+//!     if n < 2 {
+//!         n as u64
+//!     } else {
+//!         rec.call(n - 1) + rec.call(n - 2)
+//!     }
+//! });
+//! assert_eq!(fib, 2880067194370816120);
+//! assert_eq!(memo.misses(), 91);
+//! ```
+//!
+//! The cache does not know which function filled it, so a memo must only
+//! ever be used with one function, and must be [cleared](Memo::clear)
+//! whenever anything the closure captures changes, such as the input.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Debug, Clone)]
+pub struct Memo<K, V> {
+    cache: HashMap<K, V>,
+    hits: u64,
+    misses: u64,
+}
+
+impl<K, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo {
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+}
+
+impl<K: Clone + Hash + Eq, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The value of `f` at `key`. `f` gets the key and a handle for calling
+    /// itself on other keys.
+    pub fn get(&mut self, key: K, f: impl Fn(&mut Recurse<'_, K, V>, &K) -> V) -> V {
+        Recurse { memo: self, f: &f }.call(key)
+    }
+
+    /// Lookups answered from the cache.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Lookups that had to be computed.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// How many values are cached.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Forgets every cached value and resets the statistics.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+}
+
+/// The handle a memoized closure recurses through.
+pub struct Recurse<'a, K, V> {
+    memo: &'a mut Memo<K, V>,
+    f: &'a dyn Fn(&mut Recurse<'_, K, V>, &K) -> V,
+}
+
+impl<K: Clone + Hash + Eq, V: Clone> Recurse<'_, K, V> {
+    /// The value of the memoized function at `key`, from the cache if it
+    /// has been worked out before.
+    pub fn call(&mut self, key: K) -> V {
+        if let Some(value) = self.memo.cache.get(&key) {
+            self.memo.hits += 1;
+            return value.clone();
+        }
+        self.memo.misses += 1;
+        let f = self.f;
+        let value = f(self, &key);
+        self.memo.cache.insert(key, value.clone());
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(memo: &mut Memo<(u32, u32), u64>, width: u32, height: u32) -> u64 {
+        // Lattice paths from (x, y) to (width, height) moving right or down.
+        memo.get((0, 0), |rec, &(x, y)| {
+            if x == width || y == height {
+                1
+            } else {
+                rec.call((x + 1, y)) + rec.call((x, y + 1))
+            }
+        })
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut memo = Memo::new();
+        assert_eq!(paths(&mut memo, 2, 2), 6);
+        assert_eq!(memo.misses(), 8);
+        assert_eq!(memo.hits(), 1);
+        assert_eq!(memo.len(), 8);
+
+        // A second lookup of the same key is a single hit.
+        assert_eq!(paths(&mut memo, 2, 2), 6);
+        assert_eq!((memo.hits(), memo.misses()), (2, 8));
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut memo = Memo::new();
+        assert_eq!(paths(&mut memo, 16, 16), 601080390);
+        assert!(memo.hits() > 0);
+
+        memo.clear();
+        assert!(memo.is_empty());
+        assert_eq!((memo.hits(), memo.misses()), (0, 0));
+        assert_eq!(paths(&mut memo, 1, 1), 2);
+    }
+}
@@ -0,0 +1,276 @@
+//! Helpers for the shapes puzzle inputs usually come in.
+//!
+//! A [`Span`] is a piece of the input that still knows where it came from,
+//! so every failure becomes a [`ParseError`] pointing at the offending line
+//! and column. Spans split into lines, blank-line separated blocks,
+//! `label: rest` halves and delimited pieces, and the `*_with` methods run a
+//! parser over each piece and collect the results.
+//!
+//! ```
+//! use aoc_core::Span;
+//!
+//! let input = "47|53\n97|13\n\n75,47,61";
+//! let (rules, updates) = Span::new(input).split_once("\n\n").unwrap();
+//! let rules = rules.lines_with(|line| line.pair::<u32, u32>("|")).unwrap();
+//! let updates = updates.lines_with(|line| line.list::<u32>(",")).unwrap();
+//! assert_eq!(rules, vec![(47, 53), (97, 13)]);
+//! assert_eq!(updates, vec![vec![75, 47, 61]]);
+//! ```
+
+use std::any::type_name;
+use std::str::FromStr;
+
+use crate::ParseError;
+
+type Result<T> = std::result::Result<T, ParseError>;
+
+/// A slice of an input, together with the whole input it was cut from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    input: &'a str,
+    text: &'a str,
+}
+
+impl<'a> Span<'a> {
+    /// The whole of `input`.
+    pub fn new(input: &'a str) -> Self {
+        Span { input, text: input }
+    }
+
+    /// A span for `text`, which must be a slice of this span.
+    fn sub(&self, text: &'a str) -> Span<'a> {
+        Span {
+            input: self.input,
+            text,
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// The 1-based line of the input the span starts on.
+    pub fn line(&self) -> usize {
+        self.input[..offset(self.input, self.text)]
+            .matches('\n')
+            .count()
+            + 1
+    }
+
+    /// An error saying `expected` should have been where this span is.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        ParseError::at(self.input, self.text, expected)
+    }
+
+    pub fn trim(self) -> Span<'a> {
+        self.sub(self.text.trim())
+    }
+
+    pub fn lines(self) -> impl Iterator<Item = Span<'a>> {
+        self.text.lines().map(move |line| self.sub(line))
+    }
+
+    /// Runs of lines separated by one or more blank lines.
+    pub fn blocks(self) -> impl Iterator<Item = Span<'a>> {
+        let text = self.text;
+        let mut lines = text.lines().peekable();
+        std::iter::from_fn(move || {
+            while lines.next_if(|line| line.trim().is_empty()).is_some() {}
+            let first = lines.next()?;
+            let mut last = first;
+            while let Some(line) = lines.next_if(|line| !line.trim().is_empty()) {
+                last = line;
+            }
+            let start = offset(text, first);
+            let end = offset(text, last) + last.len();
+            Some(self.sub(&text[start..end]))
+        })
+    }
+
+    /// The pieces between each `delim`, trimmed, with empty pieces dropped
+    /// so that runs of spaces or a trailing delimiter do not matter. A
+    /// `delim` of `" "` splits on any whitespace.
+    pub fn split(self, delim: &'a str) -> impl Iterator<Item = Span<'a>> {
+        let pieces: Box<dyn Iterator<Item = &'a str>> = if delim == " " {
+            Box::new(self.text.split_whitespace())
+        } else {
+            Box::new(self.text.split(delim).map(str::trim))
+        };
+        pieces
+            .filter(|piece| !piece.is_empty())
+            .map(move |piece| self.sub(piece))
+    }
+
+    /// The trimmed text before and after the first `delim`.
+    pub fn split_once(self, delim: &str) -> Result<(Span<'a>, Span<'a>)> {
+        match self.text.split_once(delim) {
+            Some((left, right)) => Ok((self.sub(left).trim(), self.sub(right).trim())),
+            None => Err(self.error(format!("{:?}", delim))),
+        }
+    }
+
+    /// The trimmed text after `prefix`, which the span must start with.
+    pub fn strip_prefix(self, prefix: &str) -> Result<Span<'a>> {
+        match self.text.trim_start().strip_prefix(prefix) {
+            Some(rest) => Ok(self.sub(rest).trim()),
+            None => Err(self.error(format!("{:?}", prefix))),
+        }
+    }
+
+    /// The label and the rest of a `label: rest` line.
+    pub fn labelled(self) -> Result<(Span<'a>, Span<'a>)> {
+        self.split_once(":")
+    }
+
+    /// The trimmed text parsed as a `T`.
+    pub fn parse<T: FromStr>(self) -> Result<T> {
+        let span = self.trim();
+        span.text
+            .parse()
+            .map_err(|_| span.error(format!("a value of type {}", type_name::<T>())))
+    }
+
+    /// Every integer in the span, in order, skipping whatever is between
+    /// them. A `-` right before a number is its sign unless it follows a
+    /// digit, so `x=-3` gives -3 but `3-5` gives 3 and 5.
+    pub fn ints<T: FromStr>(self) -> Result<Vec<T>> {
+        self.integers(true)
+    }
+
+    /// Every run of digits in the span, in order, ignoring any signs.
+    pub fn uints<T: FromStr>(self) -> Result<Vec<T>> {
+        self.integers(false)
+    }
+
+    fn integers<T: FromStr>(self, signed: bool) -> Result<Vec<T>> {
+        let bytes = self.text.as_bytes();
+        let mut numbers = Vec::new();
+        let mut i = 0;
+        while i < bytes.len() {
+            if !bytes[i].is_ascii_digit() {
+                i += 1;
+                continue;
+            }
+            let mut start = i;
+            if signed
+                && start > 0
+                && bytes[start - 1] == b'-'
+                && (start < 2 || !bytes[start - 2].is_ascii_digit())
+            {
+                start -= 1;
+            }
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            numbers.push(self.sub(&self.text[start..i]).parse()?);
+        }
+        Ok(numbers)
+    }
+
+    /// Every piece between `delim`s parsed as a `T`, as with [`Span::split`].
+    pub fn list<T: FromStr>(self, delim: &'a str) -> Result<Vec<T>> {
+        self.split_with(delim, Span::parse)
+    }
+
+    /// Two values either side of `delim`, such as `3-5` or `47|53`.
+    pub fn pair<A: FromStr, B: FromStr>(self, delim: &str) -> Result<(A, B)> {
+        self.pair_with(delim, Span::parse, Span::parse)
+    }
+
+    /// Runs `parse` on every line, stopping at the first error.
+    pub fn lines_with<T>(self, parse: impl FnMut(Span<'a>) -> Result<T>) -> Result<Vec<T>> {
+        self.lines().map(parse).collect()
+    }
+
+    /// Runs `parse` on every block, stopping at the first error.
+    pub fn blocks_with<T>(self, parse: impl FnMut(Span<'a>) -> Result<T>) -> Result<Vec<T>> {
+        self.blocks().map(parse).collect()
+    }
+
+    /// Runs `parse` on every piece between `delim`s, stopping at the first
+    /// error.
+    pub fn split_with<T>(
+        self,
+        delim: &'a str,
+        parse: impl FnMut(Span<'a>) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        self.split(delim).map(parse).collect()
+    }
+
+    /// Runs `left` and `right` on either side of the first `delim`.
+    pub fn pair_with<A, B>(
+        self,
+        delim: &str,
+        left: impl FnOnce(Span<'a>) -> Result<A>,
+        right: impl FnOnce(Span<'a>) -> Result<B>,
+    ) -> Result<(A, B)> {
+        let (a, b) = self.split_once(delim)?;
+        Ok((left(a)?, right(b)?))
+    }
+}
+
+/// Where `part`, a slice of `text`, starts in it.
+fn offset(text: &str, part: &str) -> usize {
+    part.as_ptr() as usize - text.as_ptr() as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integers() {
+        let span = Span::new("x=-3, y=12..-7 and 3-5");
+        assert_eq!(span.ints::<i32>().unwrap(), vec![-3, 12, -7, 3, 5]);
+        assert_eq!(span.uints::<u32>().unwrap(), vec![3, 12, 7, 3, 5]);
+
+        let e = Span::new("ok 1\nbig 300").uints::<u8>().unwrap_err();
+        assert_eq!((e.line, e.column), (2, 5));
+        assert_eq!(e.text, "300");
+    }
+
+    #[test]
+    fn test_blocks() {
+        let input = "\na\nb\n\n\nc\r\n\r\nd\n";
+        let blocks: Vec<_> = Span::new(input).blocks().map(|b| b.as_str()).collect();
+        assert_eq!(blocks, vec!["a\nb", "c", "d"]);
+        let lines: Vec<_> = Span::new(input).blocks().map(|b| b.line()).collect();
+        assert_eq!(lines, vec![2, 6, 8]);
+    }
+
+    #[test]
+    fn test_labelled_list() {
+        let line = Span::new("Card  1: 41 48 83 | 83 86  6");
+        let (label, numbers) = line.labelled().unwrap();
+        assert_eq!(label.strip_prefix("Card").unwrap().parse::<u32>(), Ok(1));
+        let (winning, have) = numbers
+            .pair_with("|", |s| s.list::<u32>(" "), |s| s.list::<u32>(" "))
+            .unwrap();
+        assert_eq!((winning, have), (vec![41, 48, 83], vec![83, 86, 6]));
+
+        assert_eq!(Span::new("3-5").pair::<u8, u8>("-"), Ok((3, 5)));
+        assert_eq!(Span::new("a, b,").list::<char>(","), Ok(vec!['a', 'b']));
+    }
+
+    #[test]
+    fn test_errors_point_at_text() {
+        let input = "1,2\n3;4";
+        let e = Span::new(input)
+            .lines_with(|line| line.pair::<u32, u32>(","))
+            .unwrap_err();
+        assert_eq!((e.line, e.column), (2, 1));
+        assert_eq!(e.expected, "\",\"");
+
+        let e = Span::new(input)
+            .lines_with(|line| line.list::<u32>(","))
+            .unwrap_err();
+        assert_eq!((e.line, e.text.as_str()), (2, "3;4"));
+
+        let e = Span::new("Game x: 1").strip_prefix("Card").unwrap_err();
+        assert_eq!(e.expected, "\"Card\"");
+    }
+}
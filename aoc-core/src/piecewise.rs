@@ -0,0 +1,295 @@
+//! Maps on integers that shift whole intervals at once.
+//!
+//! A [`PiecewiseMap`] sends each of its source intervals onto a destination
+//! of the same length and leaves every other value where it is, like the
+//! 2023 day05 almanac maps. Maps compose into a single map, so a chain of
+//! them can be flattened once and then applied to whole interval sets.
+
+use num_traits::PrimInt;
+
+use crate::{Interval, IntervalSet};
+
+/// Sends `source.start + k` to `dest + k` for every `k` in range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Piece<T> {
+    pub source: Interval<T>,
+    pub dest: T,
+}
+
+impl<T: PrimInt> Piece<T> {
+    pub fn new(source: impl Into<Interval<T>>, dest: T) -> Self {
+        Piece {
+            source: source.into(),
+            dest,
+        }
+    }
+
+    /// Where `value`, which must lie in the source, is sent.
+    pub fn map(&self, value: T) -> T {
+        self.dest + (value - self.source.start)
+    }
+
+    /// The interval the source is sent onto.
+    pub fn image(&self) -> Interval<T> {
+        Interval::new(self.dest, self.dest + self.source.len())
+    }
+
+    fn is_identity(&self) -> bool {
+        self.dest == self.source.start
+    }
+
+    /// This piece cut down to `source`, which must lie within its own.
+    fn restrict(&self, source: Interval<T>) -> Piece<T> {
+        Piece::new(source, self.map(source.start))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PiecewiseMap<T> {
+    /// Disjoint, sorted by source, with no identity pieces.
+    pieces: Vec<Piece<T>>,
+}
+
+impl<T> Default for PiecewiseMap<T> {
+    fn default() -> Self {
+        PiecewiseMap { pieces: Vec::new() }
+    }
+}
+
+impl<T: PrimInt> PiecewiseMap<T> {
+    /// The identity map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sends `source` onto the interval starting at `dest`, replacing
+    /// whatever the map did with those values before.
+    pub fn insert(&mut self, source: impl Into<Interval<T>>, dest: T) {
+        let piece = Piece::new(source, dest);
+        if piece.source.is_empty() {
+            return;
+        }
+
+        let mut pieces = Vec::with_capacity(self.pieces.len() + 2);
+        for old in self.pieces.drain(..) {
+            if !old.source.overlaps(&piece.source) {
+                pieces.push(old);
+                continue;
+            }
+            if old.source.start < piece.source.start {
+                pieces.push(old.restrict(Interval::new(old.source.start, piece.source.start)));
+            }
+            if piece.source.end < old.source.end {
+                pieces.push(old.restrict(Interval::new(piece.source.end, old.source.end)));
+            }
+        }
+        pieces.push(piece);
+        self.pieces = normalize(pieces);
+    }
+
+    /// The pieces that move values, in order of their sources.
+    pub fn pieces(&self) -> &[Piece<T>] {
+        &self.pieces
+    }
+
+    /// Where `value` is sent, in O(log n).
+    pub fn apply(&self, value: T) -> T {
+        let i = self.pieces.partition_point(|p| p.source.end <= value);
+        match self.pieces.get(i) {
+            Some(piece) if piece.source.contains(value) => piece.map(value),
+            _ => value,
+        }
+    }
+
+    /// Where every value of `interval` is sent.
+    pub fn apply_interval(&self, interval: Interval<T>) -> IntervalSet<T> {
+        self.segments(interval).iter().map(Piece::image).collect()
+    }
+
+    /// Where every value of `set` is sent.
+    pub fn apply_set(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        set.iter()
+            .flat_map(|&interval| self.segments(interval))
+            .map(|piece| piece.image())
+            .collect()
+    }
+
+    /// The map that applies this one and then `next`.
+    pub fn then(&self, next: &PiecewiseMap<T>) -> PiecewiseMap<T> {
+        let mut pieces = Vec::new();
+
+        // Values this map moves go wherever `next` sends their image.
+        for piece in &self.pieces {
+            for segment in next.segments(piece.image()) {
+                let start = piece.source.start + (segment.source.start - piece.dest);
+                let source = Interval::new(start, start + segment.source.len());
+                pieces.push(Piece::new(source, segment.dest));
+            }
+        }
+
+        // Values this map leaves alone only go through `next`.
+        let moved: IntervalSet<T> = self.pieces.iter().map(|p| p.source).collect();
+        for piece in &next.pieces {
+            let own: IntervalSet<T> = [piece.source].into_iter().collect();
+            for rest in own.difference(&moved).iter() {
+                pieces.push(piece.restrict(*rest));
+            }
+        }
+
+        PiecewiseMap {
+            pieces: normalize(pieces),
+        }
+    }
+
+    /// The map undoing this one, or `None` if two values are sent to the
+    /// same place.
+    pub fn inverse(&self) -> Option<PiecewiseMap<T>> {
+        let sources: IntervalSet<T> = self.pieces.iter().map(|p| p.source).collect();
+        let images: IntervalSet<T> = self.pieces.iter().map(Piece::image).collect();
+        // Outside the pieces the map is the identity, so the pieces must
+        // shuffle their own sources among themselves. The sources are
+        // disjoint, so equal sets also means the images do not overlap.
+        if images != sources {
+            return None;
+        }
+
+        let pieces = self
+            .pieces
+            .iter()
+            .map(|p| Piece::new(p.image(), p.source.start))
+            .collect();
+        Some(PiecewiseMap {
+            pieces: normalize(pieces),
+        })
+    }
+
+    /// `interval` cut where the pieces start and end, each part paired with
+    /// where it is sent.
+    fn segments(&self, interval: Interval<T>) -> Vec<Piece<T>> {
+        let mut segments = Vec::new();
+        let mut start = interval.start;
+        let mut i = self.pieces.partition_point(|p| p.source.end <= start);
+        while start < interval.end {
+            let (end, dest) = match self.pieces.get(i) {
+                Some(piece) if piece.source.start <= start => {
+                    i += 1;
+                    (piece.source.end.min(interval.end), piece.map(start))
+                }
+                Some(piece) => (piece.source.start.min(interval.end), start),
+                None => (interval.end, start),
+            };
+            segments.push(Piece::new(Interval::new(start, end), dest));
+            start = end;
+        }
+        segments
+    }
+}
+
+impl<T: PrimInt, I: Into<Interval<T>>> FromIterator<(I, T)> for PiecewiseMap<T> {
+    fn from_iter<It: IntoIterator<Item = (I, T)>>(iter: It) -> Self {
+        let mut map = PiecewiseMap::new();
+        for (source, dest) in iter {
+            map.insert(source, dest);
+        }
+        map
+    }
+}
+
+/// Sorts disjoint pieces, drops the ones that move nothing and joins
+/// neighbours that shift by the same amount.
+fn normalize<T: PrimInt>(mut pieces: Vec<Piece<T>>) -> Vec<Piece<T>> {
+    pieces.retain(|p| !p.is_identity() && !p.source.is_empty());
+    pieces.sort_by_key(|p| p.source.start);
+
+    let mut joined: Vec<Piece<T>> = Vec::with_capacity(pieces.len());
+    for piece in pieces {
+        match joined.last_mut() {
+            Some(last)
+                if last.source.end == piece.source.start && last.image().end == piece.dest =>
+            {
+                last.source.end = piece.source.end;
+            }
+            _ => joined.push(piece),
+        }
+    }
+    joined
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The example's seed-to-soil and soil-to-fertilizer maps.
+    fn seed_to_soil() -> PiecewiseMap<u64> {
+        [(98..100, 50), (50..98, 52)].into_iter().collect()
+    }
+
+    fn soil_to_fertilizer() -> PiecewiseMap<u64> {
+        [(15..52, 0), (52..54, 37), (0..15, 39)]
+            .into_iter()
+            .collect()
+    }
+
+    #[test]
+    fn test_apply() {
+        let map = seed_to_soil();
+        assert_eq!(
+            [79, 14, 55, 13, 98, 100].map(|v| map.apply(v)),
+            [81, 14, 57, 13, 50, 100]
+        );
+
+        let image = map.apply_interval(Interval::new(40, 100));
+        let image: Vec<_> = image.iter().copied().collect();
+        assert_eq!(image, vec![Interval::new(40, 100)]);
+
+        let image = map.apply_interval(Interval::new(95, 101));
+        let image: Vec<_> = image.iter().copied().collect();
+        assert_eq!(image, vec![Interval::new(50, 52), Interval::new(97, 101)]);
+    }
+
+    #[test]
+    fn test_insert_replaces_overlap() {
+        let mut map: PiecewiseMap<u64> = [(0..10, 100)].into_iter().collect();
+        map.insert(3..5, 3);
+        assert_eq!(
+            map.pieces(),
+            &[Piece::new(0..3, 100), Piece::new(5..10, 105)]
+        );
+        map.insert(2..8, 200);
+        assert_eq!(
+            map.pieces(),
+            &[
+                Piece::new(0..2, 100),
+                Piece::new(2..8, 200),
+                Piece::new(8..10, 108)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_then() {
+        let (a, b) = (seed_to_soil(), soil_to_fertilizer());
+        let both = a.then(&b);
+        for v in 0..120 {
+            assert_eq!(both.apply(v), b.apply(a.apply(v)), "value {}", v);
+        }
+
+        let seeds: IntervalSet<u64> = [79..93, 55..68].into_iter().collect();
+        assert_eq!(both.apply_set(&seeds), b.apply_set(&a.apply_set(&seeds)));
+    }
+
+    #[test]
+    fn test_inverse() {
+        let map = soil_to_fertilizer();
+        let inverse = map.inverse().unwrap();
+        for v in 0..60 {
+            assert_eq!(inverse.apply(map.apply(v)), v);
+        }
+        assert_eq!(map.then(&inverse), PiecewiseMap::new());
+
+        assert!(seed_to_soil().inverse().is_some());
+        // 0 and 1 land on 5 and 6, which also stay where they are.
+        let squash: PiecewiseMap<u64> = [(0..2, 5)].into_iter().collect();
+        assert_eq!(squash.inverse(), None);
+    }
+}
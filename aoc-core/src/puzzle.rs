@@ -0,0 +1,110 @@
+use std::any::Any;
+
+use crate::{Answer, Result, Solution};
+
+/// A day's `Solution` with its parsed input type erased, so that days from
+/// every year can live in one table and be driven by the same runner.
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    /// Root of the year crate, which holds `inputs/` and `answers.toml`.
+    pub crate_dir: &'static str,
+    parse: fn(&str) -> Result<Box<dyn Any>>,
+    part1: fn(&dyn Any) -> Answer,
+    part2: fn(&dyn Any) -> Answer,
+}
+
+impl Puzzle {
+    pub fn new<S>(year: u16, day: u8, crate_dir: &'static str) -> Self
+    where
+        S: Solution,
+        S::Parsed: 'static,
+    {
+        Puzzle {
+            year,
+            day,
+            crate_dir,
+            parse: parse::<S>,
+            part1: part1::<S>,
+            part2: part2::<S>,
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        (self.parse)(input)
+    }
+
+    /// Solves `part` (1 or 2) from input returned by [`Puzzle::parse`].
+    pub fn solve(&self, parsed: &dyn Any, part: u8) -> Answer {
+        match part {
+            1 => (self.part1)(parsed),
+            2 => (self.part2)(parsed),
+            _ => panic!("part must be 1 or 2, got {}", part),
+        }
+    }
+}
+
+fn parse<S>(input: &str) -> Result<Box<dyn Any>>
+where
+    S: Solution,
+    S::Parsed: 'static,
+{
+    Ok(Box::new(S::parse(input)?))
+}
+
+fn part1<S>(parsed: &dyn Any) -> Answer
+where
+    S: Solution,
+    S::Parsed: 'static,
+{
+    S::part1(downcast::<S>(parsed))
+}
+
+fn part2<S>(parsed: &dyn Any) -> Answer
+where
+    S: Solution,
+    S::Parsed: 'static,
+{
+    S::part2(downcast::<S>(parsed))
+}
+
+fn downcast<S>(parsed: &dyn Any) -> &S::Parsed
+where
+    S: Solution,
+    S::Parsed: 'static,
+{
+    parsed
+        .downcast_ref::<S::Parsed>()
+        .expect("parsed input does not belong to this puzzle")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        type Parsed = Vec<String>;
+
+        fn parse(input: &str) -> Result<Self::Parsed> {
+            Ok(input.lines().map(|line| line.to_string()).collect())
+        }
+        fn part1(lines: &Self::Parsed) -> Answer {
+            lines.len().into()
+        }
+        fn part2(lines: &Self::Parsed) -> Answer {
+            lines.concat().into()
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let puzzle = Puzzle::new::<Lines>(2023, 1, ".");
+        let parsed = puzzle.parse("ab\ncd").unwrap();
+
+        assert_eq!(puzzle.solve(parsed.as_ref(), 1), Answer::Number(2));
+        assert_eq!(puzzle.solve(parsed.as_ref(), 2), Answer::from("abcd"));
+    }
+}
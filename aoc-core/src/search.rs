@@ -0,0 +1,423 @@
+//! Shortest-path searches over any hashable state.
+//!
+//! Every search takes the start state and a closure listing the states one
+//! move away. BFS counts moves; Dijkstra and A* take `(state, cost)` pairs and
+//! add the costs up, which must not be negative. The searches stop at the
+//! first state for which `goal` returns true.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Anything that can be summed along a path and compared. `Default` is the
+/// cost of the empty path.
+pub trait Cost: Copy + Ord + Add<Output = Self> + Default {}
+
+impl<C: Copy + Ord + Add<Output = C> + Default> Cost for C {}
+
+/// A route from the start state to a goal, both included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub states: Vec<S>,
+    pub cost: C,
+}
+
+impl<S, C> Path<S, C> {
+    pub fn start(&self) -> &S {
+        &self.states[0]
+    }
+
+    pub fn end(&self) -> &S {
+        &self.states[self.states.len() - 1]
+    }
+}
+
+/// States seen so far, each with the index of the state it was reached from.
+/// Only a tree built with `ties` keeps more than one parent per state.
+struct Tree<S, C> {
+    index: HashMap<S, usize>,
+    nodes: Vec<Node<S, C>>,
+    ties: bool,
+}
+
+struct Node<S, C> {
+    state: S,
+    parents: Vec<usize>,
+    cost: C,
+}
+
+/// What `Tree::reach` did with a newly found route to a state.
+enum Reached {
+    New(usize),
+    Shorter(usize),
+    Tied,
+    Longer,
+}
+
+impl<S: Clone + Hash + Eq, C: Cost> Tree<S, C> {
+    fn new(start: S, ties: bool) -> Self {
+        let mut index = HashMap::new();
+        index.insert(start.clone(), 0);
+        Tree {
+            index,
+            nodes: vec![Node {
+                state: start,
+                parents: Vec::new(),
+                cost: C::default(),
+            }],
+            ties,
+        }
+    }
+
+    /// Records that `state` can be reached for `cost` from node `parent`,
+    /// keeping every parent that ties for the cheapest route if the tree
+    /// tracks ties, or just the first one otherwise. Zero-cost
+    /// moves can tie a route with one of its own ancestors, or with the
+    /// start, so those are not kept: the parents never form a cycle.
+    fn reach(&mut self, state: S, parent: usize, cost: C) -> Reached {
+        let i = match self.index.entry(state) {
+            Entry::Occupied(entry) => *entry.get(),
+            Entry::Vacant(entry) => {
+                let i = self.nodes.len();
+                self.nodes.push(Node {
+                    state: entry.key().clone(),
+                    parents: vec![parent],
+                    cost,
+                });
+                entry.insert(i);
+                return Reached::New(i);
+            }
+        };
+
+        let node = &self.nodes[i];
+        if i == 0 {
+            Reached::Longer
+        } else if cost < node.cost {
+            let node = &mut self.nodes[i];
+            node.cost = cost;
+            node.parents = vec![parent];
+            Reached::Shorter(i)
+        } else if self.ties
+            && cost == node.cost
+            && !node.parents.contains(&parent)
+            && !self.descends_from(parent, i)
+        {
+            self.nodes[i].parents.push(parent);
+            Reached::Tied
+        } else {
+            Reached::Longer
+        }
+    }
+
+    /// Whether node `ancestor` is on some route from the start to node `i`,
+    /// `i` itself included.
+    fn descends_from(&self, i: usize, ancestor: usize) -> bool {
+        let mut seen = HashSet::new();
+        let mut stack = vec![i];
+        while let Some(i) = stack.pop() {
+            if i == ancestor {
+                return true;
+            }
+            if seen.insert(i) {
+                stack.extend(&self.nodes[i].parents);
+            }
+        }
+        false
+    }
+
+    /// The path to node `i` through each node's first parent.
+    fn path(&self, mut i: usize) -> Path<S, C> {
+        let cost = self.nodes[i].cost;
+        let mut states = vec![self.nodes[i].state.clone()];
+        while let Some(&parent) = self.nodes[i].parents.first() {
+            states.push(self.nodes[parent].state.clone());
+            i = parent;
+        }
+        states.reverse();
+        Path { states, cost }
+    }
+
+    /// Every path to node `i` through any of the tied parents.
+    fn paths(&self, i: usize) -> Vec<Path<S, C>> {
+        let cost = self.nodes[i].cost;
+        let mut paths = Vec::new();
+        let mut stack = vec![vec![i]];
+        while let Some(route) = stack.pop() {
+            let last = route[route.len() - 1];
+            let parents = &self.nodes[last].parents;
+            if parents.is_empty() {
+                let states = route
+                    .iter()
+                    .rev()
+                    .map(|&i| self.nodes[i].state.clone())
+                    .collect();
+                paths.push(Path { states, cost });
+            }
+            for &parent in parents {
+                let mut route = route.clone();
+                route.push(parent);
+                stack.push(route);
+            }
+        }
+        paths
+    }
+}
+
+/// Breadth-first search for the path with the fewest moves to a goal.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Hash + Eq,
+    I: IntoIterator<Item = S>,
+{
+    let mut tree = Tree::new(start, false);
+    let mut queue = VecDeque::from([0]);
+    while let Some(i) = queue.pop_front() {
+        if goal(&tree.nodes[i].state) {
+            return Some(tree.path(i));
+        }
+        let cost = tree.nodes[i].cost + 1;
+        for next in neighbours(&tree.nodes[i].state) {
+            if let Reached::New(j) = tree.reach(next, i, cost) {
+                queue.push_back(j);
+            }
+        }
+    }
+    None
+}
+
+/// The fewest moves needed to reach every state reachable from `start`.
+pub fn bfs_distances<S, I>(start: S, mut neighbours: impl FnMut(&S) -> I) -> HashMap<S, usize>
+where
+    S: Clone + Hash + Eq,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        let distance = distances[&state] + 1;
+        for next in neighbours(&state) {
+            if let Entry::Vacant(entry) = distances.entry(next) {
+                queue.push_back(entry.key().clone());
+                entry.insert(distance);
+            }
+        }
+    }
+    distances
+}
+
+/// The cheapest path to a goal.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Hash + Eq,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    astar(start, neighbours, |_| C::default(), goal)
+}
+
+/// The cheapest path to a goal, exploring states in order of their cost so
+/// far plus `heuristic`. The heuristic must never overestimate the remaining
+/// cost, or the path found may not be the cheapest.
+pub fn astar<S, C, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Hash + Eq,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut tree = Tree::new(start, false);
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&tree.nodes[0].state), C::default(), 0))]);
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        if cost > tree.nodes[i].cost {
+            continue;
+        }
+        if goal(&tree.nodes[i].state) {
+            return Some(tree.path(i));
+        }
+        for (next, step) in neighbours(&tree.nodes[i].state) {
+            let next_cost = cost + step;
+            if let Reached::New(j) | Reached::Shorter(j) = tree.reach(next, i, next_cost) {
+                let estimate = next_cost + heuristic(&tree.nodes[j].state);
+                heap.push(Reverse((estimate, next_cost, j)));
+            }
+        }
+    }
+    None
+}
+
+/// Every cheapest path to every goal that ties for the lowest cost. Costs
+/// must be positive so that tied routes are all found before a state is
+/// expanded.
+pub fn all_shortest_paths<S, C, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> Vec<Path<S, C>>
+where
+    S: Clone + Hash + Eq,
+    C: Cost,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let mut tree = Tree::new(start, true);
+    let mut heap = BinaryHeap::from([Reverse((C::default(), 0))]);
+    let mut goals = Vec::new();
+    let mut best = None;
+    while let Some(Reverse((cost, i))) = heap.pop() {
+        if cost > tree.nodes[i].cost || best.is_some_and(|best| cost > best) {
+            continue;
+        }
+        if goal(&tree.nodes[i].state) {
+            best = Some(cost);
+            goals.push(i);
+            continue;
+        }
+        for (next, step) in neighbours(&tree.nodes[i].state) {
+            let next_cost = cost + step;
+            if let Reached::New(j) | Reached::Shorter(j) = tree.reach(next, i, next_cost) {
+                heap.push(Reverse((next_cost, j)));
+            }
+        }
+    }
+    goals.into_iter().flat_map(|i| tree.paths(i)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Grid;
+
+    const MAZE: &str = "\
+S.#.
+..#.
+#...
+..#G";
+
+    fn maze() -> Grid<char> {
+        Grid::parse(MAZE, Some).unwrap()
+    }
+
+    fn open_neighbours(grid: &Grid<char>, pos: (usize, usize)) -> Vec<(usize, usize)> {
+        grid.neighbours4(pos)
+            .filter(|&next| grid[next] != '#')
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = maze();
+        let path = bfs(
+            (0, 0),
+            |&pos| open_neighbours(&grid, pos),
+            |&pos| grid[pos] == 'G',
+        )
+        .unwrap();
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.states.len(), 7);
+        assert_eq!((*path.start(), *path.end()), ((0, 0), (3, 3)));
+
+        let distances = bfs_distances((0, 0), |&pos| open_neighbours(&grid, pos));
+        assert_eq!(distances[&(3, 3)], 6);
+        assert_eq!(distances[&(0, 3)], 7);
+        assert_eq!(distances.len(), 12);
+
+        assert!(bfs((0, 0), |&pos| open_neighbours(&grid, pos), |_| false).is_none());
+    }
+
+    #[test]
+    fn test_weighted() {
+        // Stepping onto a digit costs that digit, anything else costs 1.
+        let grid = Grid::parse("1191\n9111\n1191", Some).unwrap();
+        let neighbours = |&pos: &(usize, usize)| {
+            grid.neighbours4(pos)
+                .map(|next| (next, grid[next].to_digit(10).unwrap()))
+                .collect::<Vec<_>>()
+        };
+        let goal = |&pos: &(usize, usize)| pos == (2, 3);
+
+        let path = dijkstra((0, 0), neighbours, goal).unwrap();
+        assert_eq!(path.cost, 5);
+
+        let manhattan = |&(row, col): &(usize, usize)| (2 - row + 3 - col) as u32;
+        assert_eq!(astar((0, 0), neighbours, manhattan, goal).unwrap(), path);
+    }
+
+    #[test]
+    fn test_all_shortest_paths() {
+        let grid = Grid::new(3, 3, ());
+        let paths = all_shortest_paths(
+            (0, 0),
+            |&pos| {
+                grid.neighbours4(pos)
+                    .map(|next| (next, 1))
+                    .collect::<Vec<_>>()
+            },
+            |&pos| pos == (2, 2),
+        );
+        // Choosing two downs among four moves.
+        assert_eq!(paths.len(), 6);
+        assert!(paths
+            .iter()
+            .all(|path| path.cost == 4 && path.states.len() == 5));
+
+        let mut ends: Vec<_> = all_shortest_paths(
+            (1, 1),
+            |&pos| {
+                grid.neighbours8(pos)
+                    .map(|next| (next, 1))
+                    .collect::<Vec<_>>()
+            },
+            |&(row, col)| row != 1 && col != 1,
+        )
+        .iter()
+        .map(|path| *path.end())
+        .collect();
+        ends.sort();
+        assert_eq!(ends, vec![(0, 0), (0, 2), (2, 0), (2, 2)]);
+    }
+
+    #[test]
+    fn test_ties() {
+        for ties in [false, true] {
+            let mut tree = Tree::new('a', ties);
+            tree.reach('b', 0, 1);
+            tree.reach('c', 0, 1);
+            tree.reach('d', 1, 2);
+            let tied = matches!(tree.reach('d', 2, 2), Reached::Tied);
+            assert_eq!(tied, ties);
+            assert_eq!(tree.nodes[3].parents.len(), if ties { 2 } else { 1 });
+        }
+    }
+
+    #[test]
+    fn test_zero_cost_cycle() {
+        // 0 -> 1 -> 2 and back to both 1 and 0 for free, then on to 3.
+        let neighbours = |&state: &u32| match state {
+            0 => vec![(1, 0)],
+            1 => vec![(2, 0)],
+            2 => vec![(1, 0), (0, 0), (3, 1)],
+            _ => vec![],
+        };
+        let goal = |&state: &u32| state == 3;
+
+        let path = dijkstra(0, neighbours, goal).unwrap();
+        assert_eq!((path.states, path.cost), (vec![0, 1, 2, 3], 1));
+
+        let paths = all_shortest_paths(0, neighbours, goal);
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].states, vec![0, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{Answer, Result};
+
+/// One day's puzzle, split into a parse stage and two parts that share
+/// the parsed input.
+pub trait Solution {
+    type Parsed;
+
+    fn parse(input: &str) -> Result<Self::Parsed>;
+    fn part1(parsed: &Self::Parsed) -> Answer;
+    fn part2(parsed: &Self::Parsed) -> Answer;
+}
+
+/// Parses `input` once and solves both parts.
+pub fn solve<S: Solution>(input: &str) -> Result<(Answer, Answer)> {
+    let parsed = S::parse(input)?;
+    Ok((S::part1(&parsed), S::part2(&parsed)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Parsed = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Self::Parsed> {
+            input
+                .lines()
+                .map(|line| {
+                    line.parse::<i64>()
+                        .map_err(|_| crate::ParseError::at(input, line, "an integer").into())
+                })
+                .collect()
+        }
+        fn part1(numbers: &Self::Parsed) -> Answer {
+            numbers.iter().sum::<i64>().into()
+        }
+        fn part2(_numbers: &Self::Parsed) -> Answer {
+            Answer::Unsolved
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let (part1, part2) = solve::<Sum>("1\n2\n3").unwrap();
+        assert_eq!(part1, Answer::Number(6));
+        assert_eq!(part2, Answer::Unsolved);
+    }
+
+    #[test]
+    fn test_solve_with_parse_error() {
+        assert!(solve::<Sum>("1\nx").is_err());
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Recorded answers of one year, read from the year crate's `answers.toml`:
+///
+/// ```toml
+/// [day05]
+/// part1 = 165788812
+/// part2 = 1928058
+/// ```
+///
+/// Answers that are not plain integers are written as strings.
+#[derive(Debug, Default)]
+pub struct Answers {
+    answers: HashMap<(u8, u8), String>,
+}
+
+impl Answers {
+    /// A missing file means nothing has been recorded yet, not an error.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(content) => {
+                Answers::parse(&content).map_err(|e| format!("{}: {}", path.display(), e))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(format!("{}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let table: toml::Table = content
+            .parse()
+            .map_err(|e: toml::de::Error| e.to_string())?;
+
+        let mut answers = HashMap::new();
+        for (day_key, parts) in table {
+            let day = parse_key(&day_key, "day")?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| format!("`{}` should be a table of part1/part2", day_key))?;
+
+            for (part_key, value) in parts {
+                let part = parse_key(part_key, "part")?;
+                if part != 1 && part != 2 {
+                    return Err(format!("`{}.{}` is not part1 or part2", day_key, part_key));
+                }
+                let answer = match value {
+                    toml::Value::Integer(n) => n.to_string(),
+                    toml::Value::String(s) => s.clone(),
+                    _ => {
+                        return Err(format!(
+                            "`{}.{}` should be an integer or a string",
+                            day_key, part_key
+                        ))
+                    }
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+
+        Ok(Answers { answers })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers.get(&(day, part)).map(|answer| answer.as_str())
+    }
+}
+
+/// `day5`, `day05` -> 5 for prefix `day`.
+fn parse_key(key: &str, prefix: &str) -> Result<u8, String> {
+    key.strip_prefix(prefix)
+        .and_then(|number| number.parse().ok())
+        .ok_or_else(|| format!("expected a key like `{}1`, got `{}`", prefix, key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(
+            "[day05]
+part1 = 165788812
+part2 = \"1928058\"
+
+[day1]
+part1 = \"CMZ\"
+",
+        )
+        .unwrap();
+
+        assert_eq!(answers.get(5, 1), Some("165788812"));
+        assert_eq!(answers.get(5, 2), Some("1928058"));
+        assert_eq!(answers.get(1, 1), Some("CMZ"));
+        assert_eq!(answers.get(1, 2), None);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(Answers::parse("[dayfive]\npart1 = 1").is_err());
+        assert!(Answers::parse("[day05]\npart3 = 1").is_err());
+        assert!(Answers::parse("[day05]\npart1 = 1.5").is_err());
+        assert!(Answers::parse("day05 = 1").is_err());
+    }
+
+    #[test]
+    fn test_load_missing_file() {
+        let answers = Answers::load(Path::new("does/not/exist.toml")).unwrap();
+        assert_eq!(answers.get(1, 1), None);
+    }
+}
@@ -0,0 +1,111 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use aoc_core::Puzzle;
+
+use crate::runner::catch_panic;
+
+/// Spread of the samples taken for one stage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty(), "at least one sample is needed");
+        samples.sort();
+
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+
+        Stats {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+pub struct Timings {
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+/// Times parse, part1 and part2 separately. The first `warmup` rounds are
+/// run but not recorded; each recorded round parses afresh so part1 and
+/// part2 always see the same kind of input they get from the runner. A
+/// stage that panics ends the benchmark with an error.
+pub fn bench(
+    puzzle: &Puzzle,
+    input: &str,
+    iterations: usize,
+    warmup: usize,
+) -> aoc_core::Result<Timings> {
+    let mut parse = Vec::with_capacity(iterations);
+    let mut part1 = Vec::with_capacity(iterations);
+    let mut part2 = Vec::with_capacity(iterations);
+
+    for round in 0..warmup + iterations {
+        let start = Instant::now();
+        let parsed = catch_panic(|| puzzle.parse(black_box(input)))??;
+        let parse_time = start.elapsed();
+
+        let start = Instant::now();
+        black_box(catch_panic(|| puzzle.solve(parsed.as_ref(), 1))?);
+        let part1_time = start.elapsed();
+
+        let start = Instant::now();
+        black_box(catch_panic(|| puzzle.solve(parsed.as_ref(), 2))?);
+        let part2_time = start.elapsed();
+
+        if round >= warmup {
+            parse.push(parse_time);
+            part1.push(part1_time);
+            part2.push(part2_time);
+        }
+    }
+
+    Ok(Timings {
+        parse: Stats::from_samples(parse),
+        part1: Stats::from_samples(part1),
+        part2: Stats::from_samples(part2),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_stats_odd_samples() {
+        let stats = Stats::from_samples(vec![ms(5), ms(1), ms(3)]);
+        assert_eq!(
+            stats,
+            Stats {
+                min: ms(1),
+                median: ms(3),
+                max: ms(5)
+            }
+        );
+    }
+
+    #[test]
+    fn test_stats_even_samples() {
+        let stats = Stats::from_samples(vec![ms(4), ms(1), ms(2), ms(8)]);
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.max, ms(8));
+    }
+}
@@ -0,0 +1,149 @@
+use std::time::Duration;
+
+use aoc_core::{Answer, Input, Puzzle};
+use serde_json::{json, Value};
+
+/// One line of `aoc run --format json`, describing a single part:
+///
+/// ```json
+/// {"year":2023,"day":5,"part":1,"status":"ok","answer":35,"elapsed_ms":0.012,"input":"examples/day05/1.txt"}
+/// ```
+///
+/// `status` is `ok`, `error` or `not_implemented`. Errors carry an `error`
+/// message and a null answer; `elapsed_ms` is null when the part never ran,
+/// as opposed to a part that ran and found the input had no answer.
+pub fn part(
+    puzzle: &Puzzle,
+    part: u8,
+    input: Option<&Input>,
+    outcome: Result<(&Answer, Duration), &str>,
+) -> Value {
+    let mut record = json!({
+        "year": puzzle.year,
+        "day": puzzle.day,
+        "part": part,
+        "input": input.map(|input| input.to_string()),
+    });
+
+    let fields = match outcome {
+        Ok((Answer::Unsolved, elapsed)) => json!({
+            "status": "not_implemented",
+            "answer": null,
+            "elapsed_ms": millis(elapsed),
+        }),
+        Ok((Answer::Error(error), elapsed)) => json!({
+            "status": "error",
+            "answer": null,
+            "elapsed_ms": millis(elapsed),
+            "error": error,
+        }),
+        Ok((answer, elapsed)) => json!({
+            "status": "ok",
+            "answer": answer_value(answer),
+            "elapsed_ms": millis(elapsed),
+        }),
+        Err(error) => json!({
+            "status": "error",
+            "answer": null,
+            "elapsed_ms": null,
+            "error": error,
+        }),
+    };
+    if let (Value::Object(record), Value::Object(fields)) = (&mut record, fields) {
+        record.extend(fields);
+    }
+    record
+}
+
+/// Numbers stay numbers as long as JSON readers can hold them exactly;
+/// anything wider is written as a string.
+fn answer_value(answer: &Answer) -> Value {
+    match answer {
+        Answer::Number(n) => match i64::try_from(*n) {
+            Ok(n) => Value::from(n),
+            Err(_) => Value::from(n.to_string()),
+        },
+        Answer::Text(text) => Value::from(text.as_str()),
+        Answer::Unsolved | Answer::Error(_) => Value::Null,
+    }
+}
+
+/// Milliseconds, to the microsecond.
+fn millis(elapsed: Duration) -> f64 {
+    elapsed.as_micros() as f64 / 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Dummy;
+
+    impl aoc_core::Solution for Dummy {
+        type Parsed = ();
+
+        fn parse(_input: &str) -> aoc_core::Result<Self::Parsed> {
+            Ok(())
+        }
+        fn part1(_: &Self::Parsed) -> Answer {
+            Answer::Unsolved
+        }
+        fn part2(_: &Self::Parsed) -> Answer {
+            Answer::Unsolved
+        }
+    }
+
+    fn puzzle() -> Puzzle {
+        Puzzle::new::<Dummy>(2023, 5, "/nowhere")
+    }
+
+    #[test]
+    fn test_ok() {
+        let input = Input::File("day05.txt".into());
+        let record = part(
+            &puzzle(),
+            1,
+            Some(&input),
+            Ok((&Answer::Number(35), Duration::from_millis(2))),
+        );
+        assert_eq!(
+            record,
+            json!({
+                "year": 2023,
+                "day": 5,
+                "part": 1,
+                "input": "day05.txt",
+                "status": "ok",
+                "answer": 35,
+                "elapsed_ms": 2.0,
+            })
+        );
+    }
+
+    #[test]
+    fn test_not_implemented_and_error() {
+        let record = part(&puzzle(), 2, None, Ok((&Answer::Unsolved, Duration::ZERO)));
+        assert_eq!(record["status"], "not_implemented");
+        assert_eq!(record["answer"], Value::Null);
+
+        let answer = Answer::Error("no way out".to_string());
+        let record = part(&puzzle(), 2, None, Ok((&answer, Duration::ZERO)));
+        assert_eq!(record["status"], "error");
+        assert_eq!(record["error"], "no way out");
+        assert_eq!(record["elapsed_ms"], 0.0);
+
+        let record = part(&puzzle(), 2, None, Err("no input"));
+        assert_eq!(record["status"], "error");
+        assert_eq!(record["error"], "no input");
+        assert_eq!(record["input"], Value::Null);
+    }
+
+    #[test]
+    fn test_wide_numbers_become_strings() {
+        assert_eq!(
+            answer_value(&Answer::Number(i128::MAX)),
+            Value::from(i128::MAX.to_string())
+        );
+        assert_eq!(answer_value(&Answer::Number(-3)), Value::from(-3));
+    }
+}
@@ -0,0 +1,342 @@
+mod answers;
+mod bench;
+mod json;
+mod registry;
+mod runner;
+mod selection;
+mod verify;
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use aoc_core::example::Example;
+use aoc_core::{Answer, Input, Puzzle};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+use selection::Selection;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs Advent of Code solutions from every year")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run solutions and print their answers
+    Run(RunArgs),
+    /// List the registered days of each year
+    List(ListArgs),
+    /// Check every solution against the answers recorded in answers.toml
+    Verify(VerifyArgs),
+    /// Time parse, part1 and part2 of each solution
+    Bench(BenchArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Year(s) to run, e.g. 2024, 2022..=2024 or all
+    #[arg(long, default_value = "all")]
+    year: Selection,
+    /// Day(s) to run, e.g. 7, 1..=10 or all
+    #[arg(long, default_value = "all")]
+    day: Selection,
+    /// Only run this part
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Read the puzzle input from this file, or from stdin for `-`, instead of
+    /// $AOC_INPUT_DIR/<year>/ or the year's inputs directory
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Run on fixture N from the year's examples/dayNN/ and check it against
+    /// the answers in its sidecar N.toml, running only the parts it records
+    #[arg(long, value_name = "N", conflicts_with = "input")]
+    example: Option<String>,
+    /// `json` prints one JSON object per part and line instead of text
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Args)]
+struct ListArgs {
+    /// Year(s) to list, e.g. 2024, 2022..=2024 or all
+    #[arg(long, default_value = "all")]
+    year: Selection,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Year(s) to verify, e.g. 2024, 2022..=2024 or all
+    #[arg(long, default_value = "all")]
+    year: Selection,
+    /// Day(s) to verify, e.g. 7, 1..=10 or all
+    #[arg(long, default_value = "all")]
+    day: Selection,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Year(s) to benchmark, e.g. 2024, 2022..=2024 or all
+    #[arg(long, default_value = "all")]
+    year: Selection,
+    /// Day(s) to benchmark, e.g. 7, 1..=10 or all
+    #[arg(long, default_value = "all")]
+    day: Selection,
+    /// Number of timed runs per stage
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    iterations: u64,
+    /// Number of untimed runs before measuring
+    #[arg(long, default_value_t = 1)]
+    warmup: u64,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::List(args) => list(args),
+        Command::Verify(args) => verify(args),
+        Command::Bench(args) => bench(args),
+    }
+}
+
+fn bench(args: BenchArgs) -> ExitCode {
+    let puzzles = registry::select(&args.year, &args.day);
+    if puzzles.is_empty() {
+        eprintln!("no registered solution matches the given year and day");
+        return ExitCode::FAILURE;
+    }
+
+    println!(
+        "{:<4}  {:>3}  {:<5}  {:>12}  {:>12}  {:>12}",
+        "year", "day", "stage", "min", "median", "max"
+    );
+    let mut failed = false;
+    for puzzle in &puzzles {
+        let input = match runner::resolve_input(puzzle, None) {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("{} day {:02}: {}", puzzle.year, puzzle.day, e);
+                failed = true;
+                continue;
+            }
+        };
+        let timings = input.read().and_then(|text| {
+            bench::bench(
+                puzzle,
+                &text,
+                args.iterations as usize,
+                args.warmup as usize,
+            )
+        });
+
+        match timings {
+            Ok(timings) => {
+                for (stage, stats) in [
+                    ("parse", timings.parse),
+                    ("part1", timings.part1),
+                    ("part2", timings.part2),
+                ] {
+                    println!(
+                        "{:<4}  {:>3}  {:<5}  {:>12}  {:>12}  {:>12}",
+                        puzzle.year,
+                        puzzle.day,
+                        stage,
+                        format!("{:.2?}", stats.min),
+                        format!("{:.2?}", stats.median),
+                        format!("{:.2?}", stats.max)
+                    );
+                }
+            }
+            Err(e) => {
+                eprintln!(
+                    "{} day {:02}: {}",
+                    puzzle.year,
+                    puzzle.day,
+                    runner::describe_error(&input, &e)
+                );
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn verify(args: VerifyArgs) -> ExitCode {
+    let puzzles = registry::select(&args.year, &args.day);
+    if puzzles.is_empty() {
+        eprintln!("no registered solution matches the given year and day");
+        return ExitCode::FAILURE;
+    }
+
+    let rows = match verify::verify(&puzzles) {
+        Ok(rows) => rows,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    verify::print_table(&rows);
+
+    if rows.iter().any(|row| row.status == verify::Status::Fail) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn list(args: ListArgs) -> ExitCode {
+    let puzzles = registry::select(&args.year, &Selection::All);
+    if puzzles.is_empty() {
+        eprintln!("no registered solution matches the given year");
+        return ExitCode::FAILURE;
+    }
+
+    let mut years: BTreeMap<u16, Vec<String>> = BTreeMap::new();
+    for puzzle in &puzzles {
+        years
+            .entry(puzzle.year)
+            .or_default()
+            .push(puzzle.day.to_string());
+    }
+    for (year, days) in years {
+        println!("{}: {} ({} days)", year, days.join(", "), days.len());
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn run(args: RunArgs) -> ExitCode {
+    let puzzles = registry::select(&args.year, &args.day);
+    if puzzles.is_empty() {
+        eprintln!("no registered solution matches the given year and day");
+        return ExitCode::FAILURE;
+    }
+    if (args.input.is_some() || args.example.is_some()) && puzzles.len() > 1 {
+        eprintln!("--input and --example can only be used with a single year and day");
+        return ExitCode::FAILURE;
+    }
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let mut failed = false;
+    for puzzle in &puzzles {
+        if args.format == Format::Text {
+            println!("{} day {:02}", puzzle.year, puzzle.day);
+        }
+        let example = match &args.example {
+            Some(name) => match Example::find(puzzle.year, puzzle.day, puzzle.crate_dir, name) {
+                Ok(example) => Some(example),
+                Err(e) => {
+                    report_error(args.format, puzzle, &parts, None, &e.to_string());
+                    failed = true;
+                    continue;
+                }
+            },
+            None => None,
+        };
+        let input = match &example {
+            Some(example) => Ok(example.input()),
+            None => runner::resolve_input(puzzle, args.input.as_deref()),
+        };
+        let input = match input {
+            Ok(input) => input,
+            Err(e) => {
+                report_error(args.format, puzzle, &parts, None, &e.to_string());
+                failed = true;
+                continue;
+            }
+        };
+        // an example only runs the parts its sidecar has answers for, since
+        // some examples are only valid input for one of the parts
+        let parts = match &example {
+            Some(example) if args.part.is_none() && example.has_expected() => parts
+                .iter()
+                .copied()
+                .filter(|&part| example.expected(part).is_some())
+                .collect(),
+            _ => parts.clone(),
+        };
+        match runner::run(puzzle, &parts, &input) {
+            Ok(results) => {
+                for solved in results {
+                    let answer = solved.answer.to_string();
+                    let expected = example
+                        .as_ref()
+                        .and_then(|example| example.expected(solved.part));
+                    let mismatch = expected.is_some_and(|expected| expected != answer);
+                    failed |= mismatch || matches!(solved.answer, Answer::Error(_));
+
+                    match args.format {
+                        Format::Text if mismatch => println!(
+                            "  part {}: {} (expected {})",
+                            solved.part,
+                            answer,
+                            expected.unwrap_or_default()
+                        ),
+                        Format::Text => println!("  part {}: {}", solved.part, answer),
+                        Format::Json => println!(
+                            "{}",
+                            json::part(
+                                puzzle,
+                                solved.part,
+                                Some(&input),
+                                Ok((&solved.answer, solved.elapsed))
+                            )
+                        ),
+                    }
+                }
+            }
+            Err(e) => {
+                let message = match args.format {
+                    Format::Text => runner::describe_error(&input, &e),
+                    Format::Json => e.to_string(),
+                };
+                report_error(args.format, puzzle, &parts, Some(&input), &message);
+                failed = true;
+            }
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+/// Text mode prints the error once for the day; JSON mode gives every
+/// requested part its own error record.
+fn report_error(
+    format: Format,
+    puzzle: &Puzzle,
+    parts: &[u8],
+    input: Option<&Input>,
+    message: &str,
+) {
+    match format {
+        Format::Text => eprintln!("  {}", message),
+        Format::Json => {
+            for &part in parts {
+                println!("{}", json::part(puzzle, part, input, Err(message)));
+            }
+        }
+    }
+}
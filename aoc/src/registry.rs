@@ -0,0 +1,21 @@
+use aoc_core::Puzzle;
+
+use crate::selection::Selection;
+
+pub fn puzzles() -> Vec<Puzzle> {
+    [
+        advent_of_code_2021::puzzles(),
+        advent_of_code_2022::puzzles(),
+        advent_of_code_2023::puzzles(),
+        advent_of_code_2024::puzzles(),
+        advent_of_code_2025::puzzles(),
+    ]
+    .concat()
+}
+
+pub fn select(years: &Selection, days: &Selection) -> Vec<Puzzle> {
+    puzzles()
+        .into_iter()
+        .filter(|puzzle| years.contains(puzzle.year as u32) && days.contains(puzzle.day as u32))
+        .collect()
+}
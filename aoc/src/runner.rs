@@ -0,0 +1,120 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use aoc_core::{Answer, Input, Puzzle};
+
+/// Finds the input for `puzzle`; see [`Input::resolve`] for the order in
+/// which `explicit`, `$AOC_INPUT_DIR` and the year's `inputs/` are tried.
+pub fn resolve_input(puzzle: &Puzzle, explicit: Option<&Path>) -> aoc_core::Result<Input> {
+    Input::resolve(puzzle.year, puzzle.day, puzzle.crate_dir, explicit)
+}
+
+/// Formats a failed run for the terminal. Parse errors get the offending
+/// input line with the bad text underlined rather than a one-line message.
+pub fn describe_error(input: &Input, e: &aoc_core::Error) -> String {
+    match e {
+        aoc_core::Error::Parse(e) => format!("{}: {}", input, e.diagnostic()),
+        e => format!("{}: {}", input, e),
+    }
+}
+
+pub struct Solved {
+    pub part: u8,
+    pub answer: Answer,
+    /// Time spent in the part itself, not counting reading and parsing.
+    pub elapsed: Duration,
+}
+
+/// Reads and parses the input once, then solves each of `parts` from it.
+/// A part that panics gets an [`Answer::Error`], and a panicking parse an
+/// error, so the remaining parts and puzzles still run.
+pub fn run(puzzle: &Puzzle, parts: &[u8], input: &Input) -> aoc_core::Result<Vec<Solved>> {
+    let input = input.read()?;
+    let parsed = catch_panic(|| puzzle.parse(&input))??;
+
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let answer = catch_panic(|| puzzle.solve(parsed.as_ref(), part))
+                .unwrap_or_else(|e| Answer::Error(e.to_string()));
+            Solved {
+                part,
+                answer,
+                elapsed: start.elapsed(),
+            }
+        })
+        .collect())
+}
+
+/// Runs `f`, turning a panic into an [`aoc_core::Error::Panic`].
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> aoc_core::Result<T> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .map_err(|payload| aoc_core::Error::Panic(panic_message(payload)))
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic".to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Panicky;
+
+    impl aoc_core::Solution for Panicky {
+        type Parsed = String;
+
+        fn parse(input: &str) -> aoc_core::Result<Self::Parsed> {
+            if input == "boom" {
+                panic!("cannot parse {}", input);
+            }
+            Ok(input.to_string())
+        }
+        fn part1(input: &Self::Parsed) -> Answer {
+            input.len().into()
+        }
+        fn part2(_input: &Self::Parsed) -> Answer {
+            panic!("no way out")
+        }
+    }
+
+    fn write_input(name: &str, text: &str) -> Input {
+        let path = std::env::temp_dir().join(format!("aoc-runner-{}-{}", name, std::process::id()));
+        std::fs::write(&path, text).unwrap();
+        Input::File(path)
+    }
+
+    #[test]
+    fn test_panicking_part() {
+        let puzzle = Puzzle::new::<Panicky>(2023, 17, "/nowhere");
+        let input = write_input("part", "abc");
+        let solved = run(&puzzle, &[1, 2], &input).unwrap();
+
+        assert_eq!(solved[0].answer, Answer::Number(3));
+        assert_eq!(
+            solved[1].answer,
+            Answer::Error("panicked: no way out".to_string())
+        );
+    }
+
+    #[test]
+    fn test_panicking_parse() {
+        let puzzle = Puzzle::new::<Panicky>(2023, 17, "/nowhere");
+        let input = write_input("parse", "boom");
+        match run(&puzzle, &[1, 2], &input) {
+            Err(aoc_core::Error::Panic(message)) => assert_eq!(message, "cannot parse boom"),
+            Err(e) => panic!("unexpected error: {}", e),
+            Ok(_) => panic!("the parse panic was not caught"),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+/// Years or days picked on the command line: `7`, `1..=10`, `1..10`, `all`,
+/// or a comma separated list of those such as `1,3,5..=7`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Selection {
+    All,
+    Ranges(Vec<RangeInclusive<u32>>),
+}
+
+impl Selection {
+    pub fn contains(&self, value: u32) -> bool {
+        match self {
+            Selection::All => true,
+            Selection::Ranges(ranges) => ranges.iter().any(|range| range.contains(&value)),
+        }
+    }
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim() == "all" {
+            return Ok(Selection::All);
+        }
+
+        s.split(',')
+            .map(|item| parse_range(item.trim()))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Selection::Ranges)
+    }
+}
+
+fn parse_range(item: &str) -> Result<RangeInclusive<u32>, String> {
+    let range = if let Some((start, end)) = item.split_once("..=") {
+        parse_number(start)?..=parse_number(end)?
+    } else if let Some((start, end)) = item.split_once("..") {
+        let end = parse_number(end)?;
+        if end == 0 {
+            return Err(format!("empty range `{}`", item));
+        }
+        parse_number(start)?..=end - 1
+    } else {
+        let value = parse_number(item)?;
+        value..=value
+    };
+
+    if range.is_empty() {
+        return Err(format!("empty range `{}`", item));
+    }
+    Ok(range)
+}
+
+fn parse_number(value: &str) -> Result<u32, String> {
+    value.trim().parse::<u32>().map_err(|_| {
+        format!(
+            "expected a number, `a..=b`, `a..b` or `all`, got `{}`",
+            value
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_single() {
+        assert_eq!("7".parse(), Ok(Selection::Ranges(vec![7..=7])));
+    }
+
+    #[test]
+    fn test_parse_ranges() {
+        assert_eq!("1..=10".parse(), Ok(Selection::Ranges(vec![1..=10])));
+        assert_eq!("1..10".parse(), Ok(Selection::Ranges(vec![1..=9])));
+        assert_eq!(
+            "1,3,5..=7".parse(),
+            Ok(Selection::Ranges(vec![1..=1, 3..=3, 5..=7]))
+        );
+        assert_eq!("all".parse(), Ok(Selection::All));
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!("".parse::<Selection>().is_err());
+        assert!("day7".parse::<Selection>().is_err());
+        assert!("10..=1".parse::<Selection>().is_err());
+        assert!("3..3".parse::<Selection>().is_err());
+    }
+
+    #[test]
+    fn test_contains() {
+        let selection: Selection = "1..=3,25".parse().unwrap();
+        assert!(selection.contains(2));
+        assert!(selection.contains(25));
+        assert!(!selection.contains(4));
+        assert!(Selection::All.contains(4));
+    }
+}
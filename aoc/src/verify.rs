@@ -0,0 +1,120 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+use aoc_core::Puzzle;
+
+use crate::answers::Answers;
+use crate::runner;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Missing,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail => write!(f, "FAIL"),
+            Status::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+pub struct Row {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    pub expected: Option<String>,
+    pub actual: String,
+}
+
+/// Runs every puzzle against its default input and compares the answers
+/// with the ones recorded in its year's `answers.toml`.
+pub fn verify(puzzles: &[Puzzle]) -> Result<Vec<Row>, String> {
+    let mut answers: HashMap<u16, Answers> = HashMap::new();
+    let mut rows = Vec::new();
+
+    for puzzle in puzzles {
+        let recorded = match answers.entry(puzzle.year) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let path = Path::new(puzzle.crate_dir).join("answers.toml");
+                entry.insert(Answers::load(&path)?)
+            }
+        };
+
+        let results = runner::resolve_input(puzzle, None)
+            .and_then(|input| runner::run(puzzle, &[1, 2], &input));
+        let actual: Vec<String> = match results {
+            Ok(results) => results
+                .iter()
+                .map(|solved| solved.answer.to_string())
+                .collect(),
+            Err(e) => vec![format!("error: {}", e); 2],
+        };
+
+        for (part, actual) in (1..=2).zip(actual) {
+            let expected = recorded.get(puzzle.day, part).map(|s| s.to_string());
+            let status = match &expected {
+                None => Status::Missing,
+                Some(expected) if *expected == actual => Status::Pass,
+                Some(_) => Status::Fail,
+            };
+            rows.push(Row {
+                year: puzzle.year,
+                day: puzzle.day,
+                part,
+                status,
+                expected,
+                actual,
+            });
+        }
+    }
+
+    Ok(rows)
+}
+
+pub fn print_table(rows: &[Row]) {
+    let expected_width = rows
+        .iter()
+        .map(|row| row.expected.as_deref().unwrap_or("-").len())
+        .chain(["expected".len()])
+        .max()
+        .unwrap();
+
+    println!(
+        "{:<4}  {:>3}  {:>4}  {:<7}  {:<width$}  actual",
+        "year",
+        "day",
+        "part",
+        "status",
+        "expected",
+        width = expected_width
+    );
+    for row in rows {
+        println!(
+            "{:<4}  {:>3}  {:>4}  {:<7}  {:<width$}  {}",
+            row.year,
+            row.day,
+            row.part,
+            row.status.to_string(),
+            row.expected.as_deref().unwrap_or("-"),
+            row.actual,
+            width = expected_width
+        );
+    }
+
+    let count = |status| rows.iter().filter(|row| row.status == status).count();
+    println!(
+        "\n{} passed, {} failed, {} missing",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Missing)
+    );
+}